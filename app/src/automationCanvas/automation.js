@@ -1,6 +1,22 @@
 // automation.js
 import { settings } from "../settings/settings.js";
 import { settingSelect, endX, canvas } from "./constants.js";
+import { wasmInterface } from "../wasm.js";
+
+// The value at the bottom and at the top of the canvas for every setting. Settings marked
+// `backend` change the drawing and are sent to the crate, the others are only drawn here.
+const ranges = {
+  hue: { min: 0, max: 360 },
+  scale: { min: 50, max: 350 },
+  stroke: { min: 1, max: 5 },
+  persistence: { min: 0, max: 99 },
+  threshold: { min: 0, max: 255, backend: true },
+  transformRotate: { min: -180, max: 180, initial: 0, backend: true },
+  swirl: { min: -360, max: 360, initial: 0, backend: true },
+};
+
+// Every curve between two anchors is sampled into this many linear keyframes.
+const curveSteps = 16;
 
 let automationData = defaultAutomation();
 let currentValue = "hue"; // default
@@ -26,12 +42,10 @@ function createPoint(x, y, control = null) {
 }
 
 export function defaultAutomation() {
-  let automationData = {
-    hue: [],
-    scale: [],
-    stroke: [],
-    persistence: [],
-  };
+  let automationData = {};
+  for (const setting in ranges) {
+    automationData[setting] = [];
+  }
 
   for (const setting in automationData) {
     let y = getCurrentSettingValue(setting);
@@ -44,18 +58,82 @@ export function defaultAutomation() {
 }
 
 export function getCurrentSettingValue(setting) {
-  const height = canvas.height;
-  switch (setting) {
-    case "persistence":
-      return height * (1 - settings.persistence / 99);
-    case "hue":
-      return height * (1 - settings.hue / 360);
-    case "scale":
-      return height * (1 - (settings.scale - 50) / 300);
-    case "stroke":
-      return height * (1 - (settings.stroke - 1) / 4);
-    default:
-      return height / 2;
+  const range = ranges[setting];
+  if (!range) return canvas.height / 2;
+  const value = settings[setting] ?? range.initial;
+  return canvas.height * (1 - (value - range.min) / (range.max - range.min));
+}
+
+function valueAt(setting, y) {
+  const { min, max } = ranges[setting];
+  return min + (1 - y / canvas.height) * (max - min);
+}
+
+function timeAt(x) {
+  return (x / canvas.width) * settings.clipLength;
+}
+
+// The anchors and quadratic curves of one setting as keyframes in seconds and setting units,
+// the format the crate's automation reads.
+function toKeyframes(setting) {
+  const segments = automationData[setting];
+  const keyframes = [];
+  for (let i = 0; i < segments.length - 1; i++) {
+    const [x0, y0] = segments[i].point;
+    const [x1, y1] = segments[i + 1].point;
+    const [cx, cy] = segments[i].control || [(x0 + x1) / 2, (y0 + y1) / 2];
+    for (let step = 0; step < curveSteps; step++) {
+      const t = step / curveSteps;
+      const x = (1 - t) ** 2 * x0 + 2 * (1 - t) * t * cx + t ** 2 * x1;
+      const y = (1 - t) ** 2 * y0 + 2 * (1 - t) * t * cy + t ** 2 * y1;
+      keyframes.push({ time: timeAt(x), value: valueAt(setting, y) });
+    }
+  }
+  const last = segments[segments.length - 1];
+  if (last) {
+    keyframes.push({
+      time: timeAt(last.point[0]),
+      value: valueAt(setting, last.point[1]),
+    });
+  }
+  return keyframes;
+}
+
+// A flat curve leaves the setting to its own control, so only curves that move are sent.
+function isFlat(setting) {
+  const segments = automationData[setting];
+  const y = segments[0]?.point[1];
+  return segments.every(
+    ({ point, control }) => point[1] === y && (!control || control[1] === y),
+  );
+}
+
+export function getBackendAutomation() {
+  const automation = {};
+  for (const setting in ranges) {
+    if (ranges[setting].backend && !isFlat(setting)) {
+      automation[setting] = toKeyframes(setting);
+    }
+  }
+  return automation;
+}
+
+export function hasBackendAutomation() {
+  return Object.keys(getBackendAutomation()).length > 0;
+}
+
+// Sends the curves to the crate and renders the image again with them.
+export async function sendAutomation() {
+  try {
+    await wasmInterface.set_automation(getBackendAutomation());
+    if (hasBackendAutomation()) {
+      await wasmInterface.process_image_to_automated_coords();
+    } else {
+      await wasmInterface.process_image_to_coords();
+    }
+    await wasmInterface.process_coords_to_audio();
+  } catch (err) {
+    console.error("Failed to apply automation:", err);
   }
 }
 
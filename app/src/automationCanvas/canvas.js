@@ -10,6 +10,9 @@ const settingColors = {
   scale: "#00aaff",
   stroke: "#ffaa00",
   persistence: "#ff0000",
+  threshold: "#ffffff",
+  transformRotate: "#ff00ff",
+  swirl: "#ffff00",
 };
 
 export function getCanvasCoords(e) {
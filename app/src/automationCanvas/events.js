@@ -11,6 +11,7 @@ import {
   getCurrentSettingValue,
   getAutomationData,
   getCurrentValue,
+  sendAutomation,
 } from "./automation.js";
 
 let dragging = null; // { type: 'anchor' | 'control', segmentIndex: number, pointIndex: 0 | 1 }
@@ -21,6 +22,7 @@ settingSelect.addEventListener("input", () => {
 
 clearCanvasButton.addEventListener("click", () => {
   resetAutomation();
+  sendAutomation();
 });
 
 canvas.addEventListener("contextmenu", (e) => {
@@ -86,12 +88,16 @@ canvas.addEventListener("mousemove", (e) => {
   }
 });
 
+// Every click adds, moves or removes a point, so the curves are sent once the mouse is let go.
 canvas.addEventListener("mouseup", () => {
   dragging = null;
+  sendAutomation();
 });
 
 canvas.addEventListener("mouseleave", () => {
+  if (!dragging) return;
   dragging = null;
+  sendAutomation();
 });
 
 function distance([x1, y1], [x2, y2]) {
@@ -7,12 +7,20 @@ const input = document.getElementById("upload");
 // Handle upload event.
 input.addEventListener("change", (event) => {
   console.log("file uploaded");
-  const file = event.target.files[0];
+  const files = Array.from(event.target.files);
+  const file = files[0];
   if (!file) return;
   const type = file.type;
 
-  if (type.startsWith("audio/")) {
+  const sequence = files.length > 1 && files.every((f) => f.type.startsWith("image/"));
+  if (sequence || type === "image/gif") {
+    framesToBackend(files);
+  } else if (file.name.toLowerCase().endsWith(".obj")) {
+    meshToBackend(file);
+  } else if (type.startsWith("audio/")) {
     audioToBackend(file);
+  } else if (type === "image/svg+xml") {
+    svgToBackend(file);
   } else if (type.startsWith("image/")) {
     imageToBackend(file);
   } else {
@@ -25,11 +33,12 @@ export async function audioToBackend(file) {
   const arrayBuffer = await file.arrayBuffer();
   // Convert to Uint8Array
   const uint8Array = new Uint8Array(arrayBuffer);
-  // Send to backend
+  // Send to backend. Only the raw bytes are kept, the player fetches windows of them, so long
+  // files are never decoded as a whole.
   try {
     await wasmInterface.audio_to_backend(uint8Array);
-    await wasmInterface.process_audio_to_coords();
-    await wasmInterface.process_coords_to_audio();
+    // Reading the length checks the file is a stereo WAV the windows can be read from.
+    await wasmInterface.get_audio_duration();
   } catch (e) {
     console.error("Error uploading image", e);
   }
@@ -51,3 +60,48 @@ export async function imageToBackend(file) {
     console.error("Error uploading image:", e);
   }
 }
+
+// Send svg to backend, svgs are read as vectors and skip image tracing.
+export async function svgToBackend(file) {
+  try {
+    const arrayBuffer = await file.arrayBuffer();
+    const uint8Array = new Uint8Array(arrayBuffer);
+    await wasmInterface.svg_to_backend(uint8Array);
+    await wasmInterface.process_svg_to_coords();
+    await wasmInterface.process_coords_to_audio();
+  } catch (e) {
+    console.error("Error uploading svg:", e);
+  }
+}
+
+// Send animation frames to backend. Image sequences are sorted by the numbers in their names,
+// so frame_2.png comes before frame_10.png.
+export async function framesToBackend(files) {
+  try {
+    const sorted = [...files].sort((a, b) =>
+      a.name.localeCompare(b.name, undefined, { numeric: true }),
+    );
+    const frames = await Promise.all(
+      sorted.map(async (f) => new Uint8Array(await f.arrayBuffer())),
+    );
+    await wasmInterface.frames_to_backend(frames);
+    await wasmInterface.process_frames_to_coords();
+    await wasmInterface.process_coords_to_audio();
+  } catch (e) {
+    console.error("Error uploading frames:", e);
+  }
+}
+
+// Send an obj mesh to backend, the mesh is drawn as a rotating wireframe.
+export async function meshToBackend(file) {
+  try {
+    const arrayBuffer = await file.arrayBuffer();
+    const uint8Array = new Uint8Array(arrayBuffer);
+    await wasmInterface.mesh_to_backend(uint8Array);
+    await wasmInterface.set_wireframe("obj");
+    await wasmInterface.process_mesh_to_coords();
+    await wasmInterface.process_coords_to_audio();
+  } catch (e) {
+    console.error("Error uploading mesh:", e);
+  }
+}
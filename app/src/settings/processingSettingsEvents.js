@@ -1,5 +1,6 @@
 import { wasmInterface } from "../wasm.js";
 import { updateDirectionPad } from "./directionPad.js";
+import { hasBackendAutomation } from "../automationCanvas/automation.js";
 
 const processingSettings = [
   {
@@ -141,7 +142,11 @@ document
 
 async function reprocess() {
   try {
-    await wasmInterface.process_image_to_coords();
+    if (hasBackendAutomation()) {
+      await wasmInterface.process_image_to_automated_coords();
+    } else {
+      await wasmInterface.process_image_to_coords();
+    }
     await wasmInterface.process_coords_to_audio();
   } catch (err) {
     console.error("Failed to process image or coords to audio:", err);
@@ -1,4 +1,10 @@
 import { wasmInterface } from "../wasm.js";
+import { settings } from "./settings.js";
+import { seek } from "../state.js";
+import {
+  hasBackendAutomation,
+  sendAutomation,
+} from "../automationCanvas/automation.js";
 
 const styleSettings = [
   {
@@ -64,7 +70,15 @@ styleSettings.forEach(({ id, key, setter, events = ["input"] }) => {
 
 const clipLength = document.getElementById("clipLength");
 clipLength.addEventListener("change", async (event) => {
-  await wasmInterface.set_clip_length(parseInt(clipLength.value));
+  settings.clipLength = parseInt(clipLength.value);
+  await wasmInterface.set_clip_length(settings.clipLength);
+  // The curves are stretched over the clip, so their keyframes move with its length.
+  if (hasBackendAutomation()) await sendAutomation();
+});
+
+const seekRange = document.getElementById("seek");
+seekRange.addEventListener("change", async () => {
+  await seek(parseFloat(seekRange.value));
 });
 
 document.querySelectorAll('input[name="drawmode"]').forEach((el) => {
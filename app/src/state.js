@@ -11,6 +11,7 @@ import { settings } from "./settings/settings.js";
 let audioBuffer = null;
 let animationId = null;
 let sourceNode = null;
+let seekPosition = 0; // seconds
 
 export const audioContext = new AudioContext();
 
@@ -56,27 +57,55 @@ export function getSourceNode() {
   return sourceNode;
 }
 
-export async function startPlayback() {
-  console.log("start playback");
-
-  // Fetch raw WAV audio bytes from backend (Uint8Array)
-  let audioBytes;
+// Moves playback to a fraction of the audio, a running clip restarts at the new position.
+export async function seek(fraction) {
   try {
-    audioBytes = await wasmInterface.get_audio();
+    const duration = await wasmInterface.get_audio_duration();
+    seekPosition = fraction * duration;
   } catch (err) {
-    console.error("Failed to fetch audio from backend:", err);
+    console.error("Failed to seek:", err);
     return;
   }
+  if (state.playing) {
+    stopPlayback();
+    await startPlayback();
+  }
+}
 
-  // Convert Uint8Array to ArrayBuffer
-  const arrayBuffer = audioBytes.buffer.slice(0);
+// Only one clip of the audio is fetched from the seek position on, so long files never have
+// to be decoded as a whole.
+async function fetchClip() {
+  const duration = await wasmInterface.get_audio_duration();
+  const sampleRate = await wasmInterface.get_audio_sample_rate();
+  const start = Math.min(
+    seekPosition,
+    Math.max(duration - settings.clipLength, 0),
+  );
+  const points = await wasmInterface.get_audio_window(
+    start,
+    start + settings.clipLength,
+    0,
+  );
+
+  const length = Math.max(points.length / 2, 1);
+  const buffer = audioContext.createBuffer(2, length, sampleRate);
+  const left = buffer.getChannelData(0);
+  const right = buffer.getChannelData(1);
+  for (let i = 0; i < points.length / 2; i++) {
+    left[i] = points[i * 2];
+    right[i] = points[i * 2 + 1];
+  }
+  return buffer;
+}
+
+export async function startPlayback() {
+  console.log("start playback");
 
-  // Decode WAV bytes into AudioBuffer
   let audioBuffer;
   try {
-    audioBuffer = await audioContext.decodeAudioData(arrayBuffer);
+    audioBuffer = await fetchClip();
   } catch (err) {
-    console.error("Error decoding audio:", err);
+    console.error("Failed to fetch audio from backend:", err);
     return;
   }
 
@@ -101,9 +130,7 @@ export async function startPlayback() {
   source.connect(audioContext.destination);
   source.start();
 
-  const dataL = new Float32Array(analyserL.fftSize);
-  const dataR = new Float32Array(analyserR.fftSize);
-  startVisualization(analyserL, analyserR, dataL, dataR);
+  startVisualization(analyserL, analyserR);
   startPlayhead();
 
   state.playing = true;
@@ -1,8 +1,12 @@
 import { clearAnimation, getSourceNode, setAnimationId } from "../state.js";
 import { ctx, canvas } from "./canvas.js";
 import { updateColor, settings } from "../settings/settings.js";
-import { wasmInterface } from "../wasm.js";
+import { Visualizer, wasmFloats } from "../wasm.js";
 const blackPointSet = new Set();
+let visualizer = null;
+// Seconds of samples kept after they left the analyser window, enough for
+// persistence 99 to fade out at 60 frames per second.
+const HISTORY_SECONDS = 10;
 
 export function setBlackPoints(points) {
   blackPointSet.clear();
@@ -20,10 +24,20 @@ export function setBlackPoints(points) {
 }
 
 // takes an audio buffer and uses stereo waveform data to render visuals.
-export function startVisualization(analyserL, analyserR, dataL, dataR) {
+export function startVisualization(analyserL, analyserR) {
   // Clear any existing animation frame
   clearAnimation();
 
+  // The old visualizer holds wasm memory until it is freed
+  visualizer?.free();
+  const audioContext = analyserL.context;
+  visualizer = new Visualizer(
+    analyserL.fftSize,
+    Math.ceil(audioContext.sampleRate * HISTORY_SECONDS),
+  );
+  const size = visualizer.block_size();
+  let lastTime = audioContext.currentTime;
+
   draw();
 
   // Draw function renders one frame and schedules next
@@ -35,47 +49,62 @@ export function startVisualization(analyserL, analyserR, dataL, dataR) {
     const id = requestAnimationFrame(draw);
     setAnimationId(id);
 
-    // Populate data arrays with latest waveform samples
-    analyserL.getFloatTimeDomainData(dataL);
-    analyserR.getFloatTimeDomainData(dataR);
-
-    // Draw trailing effect
-    ctx.fillStyle = `rgba(0, 0, 0, ${1 - settings.persistence / 100})`;
+    // Write the latest waveform samples straight into the visualizer
+    analyserL.getFloatTimeDomainData(wasmFloats(visualizer.left_ptr(), size));
+    analyserR.getFloatTimeDomainData(wasmFloats(visualizer.right_ptr(), size));
+
+    visualizer.set_view(settings.scale, settings.centerX, settings.centerY);
+    visualizer.set_noise(settings.noise);
+    visualizer.set_persistence(settings.persistence);
+
+    // The samples that arrived since the last frame pushed as many out of the
+    // window
+    const now = audioContext.currentTime;
+    const fresh = Math.round((now - lastTime) * audioContext.sampleRate);
+    lastTime = now;
+    const count = visualizer.update(Math.min(Math.max(fresh, 0), size));
+    // Every vertex is x, y and the weight persistence left of it
+    const vertices = wasmFloats(visualizer.vertices_ptr(), count * 3);
+
+    ctx.globalAlpha = 1;
+    ctx.fillStyle = "black";
     ctx.fillRect(0, 0, canvas.width, canvas.height);
 
-    // Generate XY points from waveform data
-    const points = wasmInterface.generate_points(
-      dataL,
-      dataR,
-      settings.scale,
-      settings.centerX,
-      settings.centerY,
-    );
-
-    if (settings.dotMode) {
-      updateColor();
-      ctx.fillStyle = settings.lineColor;
-      ctx.beginPath();
-      for (let i = 0; i < points.length; i += 2) {
-        const x = points[i];
-        const y = points[i + 1];
-        if (!shouldDraw(x, y)) continue;
-
-        ctx.moveTo(x, y);
-        ctx.arc(x, y, settings.stroke, 0, Math.PI * 2);
-      }
-      ctx.fill();
-    } else {
-      updateColor();
-      ctx.strokeStyle = settings.lineColor;
-      ctx.lineWidth = settings.stroke;
+    updateColor();
+    ctx.fillStyle = settings.lineColor;
+    ctx.strokeStyle = settings.lineColor;
+    ctx.lineWidth = settings.stroke;
+    // Vertices with the same weight left the window in the same frame, so they
+    // are drawn as one path
+    let start = 0;
+    while (start < count) {
+      const weight = vertices[start * 3 + 2];
+      let end = start + 1;
+      while (end < count && vertices[end * 3 + 2] === weight) end++;
+      ctx.globalAlpha = weight;
       ctx.beginPath();
-      ctx.moveTo(points[0], points[1]);
-      for (let i = 2; i < points.length; i += 2) {
-        ctx.lineTo(points[i], points[i + 1]);
+      if (settings.dotMode) {
+        for (let i = start; i < end; i++) {
+          const x = vertices[i * 3];
+          const y = vertices[i * 3 + 1];
+          if (!shouldDraw(x, y)) continue;
+
+          ctx.moveTo(x, y);
+          ctx.arc(x, y, settings.stroke, 0, Math.PI * 2);
+        }
+        ctx.fill();
+      } else {
+        // Lines continue from the last vertex of the path before
+        const first = Math.max(start - 1, 0);
+        ctx.moveTo(vertices[first * 3], vertices[first * 3 + 1]);
+        for (let i = first + 1; i < end; i++) {
+          ctx.lineTo(vertices[i * 3], vertices[i * 3 + 1]);
+        }
+        ctx.stroke();
       }
-      ctx.stroke();
+      start = end;
     }
+    ctx.globalAlpha = 1;
   }
 }
 
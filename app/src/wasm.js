@@ -1,7 +1,9 @@
-import init, { JsInterface } from "../pkg/oscilloscope.js";
+import init, { JsInterface, Visualizer } from "../pkg/oscilloscope.js";
+
+let wasm = null;
 
 export async function setupWasm() {
-  await init();
+  wasm = await init();
   console.log("WASM initialized successfully");
 
   const wasmInterface = new JsInterface();
@@ -12,4 +14,12 @@ export async function setupWasm() {
   return wasmInterface;
 }
 
+// Views on the wasm memory are detached when the memory grows, so a new view is made for
+// every use instead of keeping one around.
+export function wasmFloats(ptr, length) {
+  return new Float32Array(wasm.memory.buffer, ptr, length);
+}
+
+export { Visualizer };
+
 export const wasmInterface = await setupWasm();
@@ -0,0 +1,2 @@
+mod pitch;
+pub mod request;
@@ -0,0 +1,102 @@
+use realfft::RealFftPlanner;
+
+// Fastest repetition that is searched for, in Hz. The slowest one is set by the window, the
+// figure has to repeat at least twice in it, so a traced drawing of 10000 samples is found in
+// windows of 20000 samples or more.
+const MAX_FREQUENCY: f32 = 5000.0;
+// The first peak that reaches this part of the highest peak is the period, which keeps the
+// estimate from jumping to a multiple of the period.
+const PEAK_RATIO: f32 = 0.9;
+// Below this clarity there is no repeating figure in the window.
+const MIN_CLARITY: f32 = 0.5;
+
+/// Estimates how often the figure in a window repeats with the normalized square difference
+/// function of McLeod and Wyvill, taken over both channels, so the figure has to repeat as a
+/// whole. Returns the period in samples and how clearly it repeats, from 0.0 to 1.0.
+pub fn period(window: &[(f32, f32)], sample_rate: u32) -> Option<(f32, f32)> {
+    let len = window.len();
+    let min_lag = ((sample_rate as f32 / MAX_FREQUENCY) as usize).max(2);
+    let max_lag = len / 2;
+    if min_lag + 2 >= max_lag {
+        return None;
+    }
+
+    let left: Vec<f32> = window.iter().map(|p| p.0).collect();
+    let right: Vec<f32> = window.iter().map(|p| p.1).collect();
+    let acf: Vec<f32> = autocorrelation(&left)
+        .iter()
+        .zip(autocorrelation(&right))
+        .map(|(l, r)| l + r)
+        .collect();
+
+    // Energy of the two overlapping parts for every lag, built up from the full energy.
+    let squares: Vec<f32> = window.iter().map(|(x, y)| x * x + y * y).collect();
+    let mut energy = 2.0 * acf[0];
+    let mut nsdf = vec![0.0; max_lag + 1];
+    for lag in 0..=max_lag {
+        if lag > 0 {
+            energy -= squares[lag - 1] + squares[len - lag];
+        }
+        nsdf[lag] = match energy > f32::EPSILON {
+            true => 2.0 * acf[lag] / energy,
+            false => 0.0,
+        };
+    }
+
+    // Highest point of every positive lobe after the first crossing below zero.
+    let mut peaks: Vec<usize> = vec![];
+    let mut lobe: Option<usize> = None;
+    let mut crossed = false;
+    for lag in 1..=max_lag {
+        if nsdf[lag] <= 0.0 {
+            crossed = true;
+            if let Some(peak) = lobe.take() {
+                peaks.push(peak);
+            }
+        } else if crossed && lag >= min_lag && lobe.is_none_or(|peak| nsdf[lag] > nsdf[peak]) {
+            lobe = Some(lag);
+        }
+    }
+    peaks.extend(lobe);
+
+    let highest = peaks.iter().map(|&lag| nsdf[lag]).fold(0.0, f32::max);
+    let lag = *peaks
+        .iter()
+        .find(|&&lag| nsdf[lag] >= highest * PEAK_RATIO)?;
+    if nsdf[lag] < MIN_CLARITY {
+        return None;
+    }
+
+    // Parabolic interpolation between the neighbouring lags.
+    let (a, b, c) = (nsdf[lag - 1], nsdf[lag], nsdf[(lag + 1).min(max_lag)]);
+    let denominator = a - 2.0 * b + c;
+    let shift = match denominator.abs() > f32::EPSILON {
+        true => (0.5 * (a - c) / denominator).clamp(-0.5, 0.5),
+        false => 0.0,
+    };
+    Some((lag as f32 + shift, b))
+}
+
+// Autocorrelation of every lag through the FFT, the signal is padded so it does not wrap.
+fn autocorrelation(signal: &[f32]) -> Vec<f32> {
+    let size = (signal.len() * 2).next_power_of_two();
+    let mut planner = RealFftPlanner::<f32>::new();
+    let forward = planner.plan_fft_forward(size);
+    let inverse = planner.plan_fft_inverse(size);
+
+    let mut input = forward.make_input_vec();
+    input[..signal.len()].copy_from_slice(signal);
+    let mut spectrum = forward.make_output_vec();
+    // The buffers have the lengths the plans ask for, so processing can not fail.
+    forward
+        .process(&mut input, &mut spectrum)
+        .unwrap_or_default();
+    for bin in spectrum.iter_mut() {
+        *bin = realfft::num_complex::Complex::new(bin.norm_sqr(), 0.0);
+    }
+    let mut output = inverse.make_output_vec();
+    inverse
+        .process(&mut spectrum, &mut output)
+        .unwrap_or_default();
+    output.iter().map(|v| v / size as f32).collect()
+}
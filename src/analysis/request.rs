@@ -0,0 +1,135 @@
+use crate::analysis::pitch::period;
+use serde::Serialize;
+
+// Two cycles that differ by more than this part of their energy are different frames.
+const FRAME_CHANGE: f32 = 0.1;
+
+/// Levels and repetition of a single window of the stream.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Window {
+    pub start: usize,
+    pub rms: (f32, f32),
+    /// Correlation between the channels, 1.0 is a line from bottom left to top right, -1.0 a
+    /// line from top left to bottom right and 0.0 a figure that uses both directions equally.
+    pub correlation: f32,
+    /// How many times per second the figure repeats, when it repeats.
+    pub fundamental: Option<f32>,
+    pub clarity: f32,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Analysis {
+    pub sample_rate: u32,
+    pub window_size: usize,
+    pub windows: Vec<Window>,
+    /// The fundamental of the windows that repeat most clearly, weighted by their clarity.
+    pub fundamental: Option<f32>,
+    /// Samples where a repeating figure changes into a different one.
+    pub frame_boundaries: Vec<usize>,
+}
+
+pub struct Request<C: std::ops::Deref<Target = Vec<(f32, f32)>>> {
+    pub coords: C,
+    pub sample_rate: u32,
+    pub window_size: usize,
+    pub result: Analysis,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<C: std::ops::Deref<Target = Vec<(f32, f32)>>> Request<C> {
+    pub fn process(&mut self) {
+        let window_size = self.window_size.max(2);
+        let windows: Vec<Window> = self
+            .coords
+            .chunks(window_size)
+            .enumerate()
+            .map(|(i, window)| self.window(i * window_size, window))
+            .collect();
+
+        let (weighted, weights) = windows
+            .iter()
+            .filter_map(|w| w.fundamental.map(|f| (f * w.clarity, w.clarity)))
+            .fold((0.0, 0.0), |(sum, weights), (f, w)| (sum + f, weights + w));
+
+        self.result = Analysis {
+            sample_rate: self.sample_rate,
+            window_size,
+            frame_boundaries: self.frame_boundaries(&windows, window_size),
+            fundamental: (weights > 0.0).then(|| weighted / weights),
+            windows,
+        };
+    }
+
+    fn window(&self, start: usize, window: &[(f32, f32)]) -> Window {
+        let n = window.len().max(1) as f32;
+        let mean = |f: fn(&(f32, f32)) -> f32| window.iter().map(f).sum::<f32>() / n;
+        let (mean_x, mean_y) = (mean(|p| p.0), mean(|p| p.1));
+        let (xx, yy, xy) = window.iter().fold((0.0, 0.0, 0.0), |(xx, yy, xy), (x, y)| {
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            (xx + dx * dx, yy + dy * dy, xy + dx * dy)
+        });
+        let correlation = match xx * yy > f32::EPSILON {
+            true => xy / (xx * yy).sqrt(),
+            false => 0.0,
+        };
+
+        let period = period(window, self.sample_rate);
+        Window {
+            start,
+            rms: (mean(|p| p.0 * p.0).sqrt(), mean(|p| p.1 * p.1).sqrt()),
+            correlation,
+            fundamental: period.map(|(period, _)| self.sample_rate as f32 / period),
+            clarity: period.map(|(_, clarity)| clarity).unwrap_or(0.0),
+        }
+    }
+
+    // Walks the stream one cycle at a time, with the period of the window the cycle starts in,
+    // and compares every cycle with the one before it. A boundary is where a figure that was
+    // repeating turns into a cycle that does not match.
+    fn frame_boundaries(&self, windows: &[Window], window_size: usize) -> Vec<usize> {
+        let mut boundaries = vec![];
+        let mut repeating = false;
+        let mut position = 0;
+        while let Some(window) = windows.get(position / window_size) {
+            let Some(fundamental) = window.fundamental else {
+                repeating = false;
+                position = window.start + window_size;
+                continue;
+            };
+            let period = ((self.sample_rate as f32 / fundamental).round() as usize).max(1);
+            if position + 2 * period > self.coords.len() {
+                break;
+            }
+
+            let previous = &self.coords[position..position + period];
+            let current = &self.coords[position + period..position + 2 * period];
+            let matches = difference(previous, current) <= FRAME_CHANGE;
+            if repeating && !matches {
+                boundaries.push(position + period);
+            }
+            repeating = matches;
+            position += period;
+        }
+        boundaries
+    }
+}
+
+// Energy of the difference between two cycles, relative to the energy of the cycles.
+fn difference(a: &[(f32, f32)], b: &[(f32, f32)]) -> f32 {
+    let (difference, energy) =
+        a.iter()
+            .zip(b)
+            .fold((0.0, 0.0), |(difference, energy), ((ax, ay), (bx, by))| {
+                (
+                    difference + (ax - bx).powi(2) + (ay - by).powi(2),
+                    energy + ax * ax + ay * ay + bx * bx + by * by,
+                )
+            });
+    match energy > f32::EPSILON {
+        true => difference / energy,
+        false => 0.0,
+    }
+}
@@ -4,16 +4,17 @@ use std::io::Cursor;
 pub struct Request<A: std::ops::Deref<Target = Vec<u8>>> {
     pub audio: A,
     pub result: Vec<(f32, f32)>,
+    pub sample_rate: u32,
 }
 
 /// Every request gets handeled in the Request struct for easy accessibility to variables and to
 /// make it easy to refactor or add useability later.
 impl<A: std::ops::Deref<Target = Vec<u8>>> Request<A> {
     pub fn process(&mut self) -> Result<(), anyhow::Error> {
-        let reader = hound::WavReader::new(Cursor::new(self.audio.clone()))?;
+        let reader = hound::WavReader::new(Cursor::new(self.audio.as_slice()))?;
         let spec = reader.spec();
         let num_channels = spec.channels;
-        let _sample_rate = spec.sample_rate;
+        self.sample_rate = spec.sample_rate;
 
         if num_channels != 2 {
             return Err(anyhow::anyhow!("Only stereo WAV files are supported"));
@@ -0,0 +1,76 @@
+use crate::backend::process_request::audio_to_coords_request;
+use realfft::RealFftPlanner;
+use std::f32::consts::PI;
+
+// Level the magnitudes are clamped to, so silence does not end up at minus infinity.
+const FLOOR_DB: f32 = -120.0;
+
+pub struct Request<A: std::ops::Deref<Target = Vec<u8>>> {
+    pub audio: A,
+    pub fft_size: usize,
+    pub hop: usize,
+    /// Magnitudes in dB of every frame, `fft_size / 2 + 1` bins from 0 Hz up to nyquist.
+    pub result: Vec<Vec<f32>>,
+}
+
+/// Hann window of a given size.
+pub fn hann(size: usize) -> Vec<f32> {
+    (0..size)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / size as f32).cos())
+        .collect()
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<A: std::ops::Deref<Target = Vec<u8>>> Request<A> {
+    // The channels are mixed to mono and cut into windowed frames. A magnitude of 0 dB is a full
+    // scale sine.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let mut request = audio_to_coords_request(&*self.audio);
+        request.process()?;
+        let signal: Vec<f32> = request.result.iter().map(|(x, y)| (x + y) / 2.0).collect();
+
+        let size = self.fft_size.max(2) & !1;
+        let hop = self.hop.max(1);
+        let window = hann(size);
+        let scale = 2.0 / window.iter().sum::<f32>();
+        let fft = RealFftPlanner::<f32>::new().plan_fft_forward(size);
+        let mut input = fft.make_input_vec();
+        let mut output = fft.make_output_vec();
+
+        self.result = Vec::with_capacity(signal.len() / hop + 1);
+        let mut start = 0;
+        while start + size <= signal.len().max(size) {
+            for (i, sample) in input.iter_mut().enumerate() {
+                *sample = signal.get(start + i).copied().unwrap_or(0.0) * window[i];
+            }
+            fft.process(&mut input, &mut output)?;
+            self.result.push(
+                output
+                    .iter()
+                    .map(|bin| (20.0 * (bin.norm() * scale).log10()).max(FLOOR_DB))
+                    .collect(),
+            );
+            start += hop;
+        }
+        Ok(())
+    }
+
+    /// The spectrum of the whole clip, the power of every bin averaged over all frames.
+    pub fn average(&self) -> Vec<f32> {
+        let Some(first) = self.result.first() else {
+            return vec![];
+        };
+        let frames = self.result.len() as f32;
+        (0..first.len())
+            .map(|bin| {
+                let power: f32 = self
+                    .result
+                    .iter()
+                    .map(|frame| 10f32.powf(frame[bin] / 10.0))
+                    .sum();
+                (10.0 * (power / frames).log10()).max(FLOOR_DB)
+            })
+            .collect()
+    }
+}
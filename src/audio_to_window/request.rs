@@ -0,0 +1,112 @@
+use hound::{SampleFormat, WavReader};
+use std::io::Cursor;
+
+pub struct Request<A: std::ops::Deref<Target = Vec<u8>>> {
+    pub audio: A,
+    /// Start and end of the window in seconds, the end is not included.
+    pub start: f64,
+    pub end: f64,
+    /// Most points the window returns, longer windows are decimated to the points with the
+    /// lowest and highest x and y of every bucket. 0 returns every point.
+    pub max_points: usize,
+    /// Interleaved x and y of every point in the window.
+    pub result: Vec<f32>,
+    pub sample_rate: u32,
+    /// Length of the whole file in points.
+    pub frames: u32,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<A: std::ops::Deref<Target = Vec<u8>>> Request<A> {
+    // Only the points of the window are decoded, straight from the WAV bytes, so the memory
+    // used stays with the size of the window and not the size of the file. Decimated windows
+    // split the window into one bucket per four points and keep the points with the lowest x,
+    // highest x, lowest y and highest y of every bucket in the order they are played. Every
+    // returned point is a real point of the figure and the full swing of both channels is kept
+    // instead of aliased away.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let mut reader = WavReader::new(Cursor::new(self.audio.as_slice()))?;
+        let spec = reader.spec();
+        if spec.channels != 2 {
+            return Err(anyhow::anyhow!("Only stereo WAV files are supported"));
+        }
+        self.sample_rate = spec.sample_rate;
+        self.frames = reader.duration();
+
+        let to_frame = |time: f64| {
+            ((time.max(0.0) * spec.sample_rate as f64).round() as u64).min(self.frames as u64)
+                as u32
+        };
+        let (first, last) = (to_frame(self.start), to_frame(self.end));
+        let count = last.saturating_sub(first) as usize;
+        let points = match self.max_points {
+            0 => count,
+            max_points => count.min(max_points),
+        };
+
+        let mut result = Vec::with_capacity(points * 2);
+        reader.seek(first)?;
+        if points == count {
+            read(&mut reader, count, |x, y| result.extend([x, y]))?;
+        } else {
+            let buckets = (points / 4).max(1);
+            // Index and point of the lowest x, highest x, lowest y and highest y of the bucket.
+            let mut extremes: Option<[(usize, (f32, f32)); 4]> = None;
+            let mut read_points = 0;
+            read(&mut reader, count, |x, y| {
+                let point = (read_points, (x, y));
+                let e = extremes.get_or_insert([point; 4]);
+                if x < e[0].1.0 {
+                    e[0] = point;
+                }
+                if x > e[1].1.0 {
+                    e[1] = point;
+                }
+                if y < e[2].1.1 {
+                    e[2] = point;
+                }
+                if y > e[3].1.1 {
+                    e[3] = point;
+                }
+                read_points += 1;
+                // A bucket ends where the next bucket starts.
+                if read_points == (result.len() / 8 + 1) * count / buckets {
+                    let mut e = extremes.take().unwrap();
+                    e.sort_by_key(|(index, _)| *index);
+                    result.extend(e.iter().flat_map(|(_, (x, y))| [*x, *y]));
+                }
+            })?;
+        }
+        self.result = result;
+        Ok(())
+    }
+}
+
+// Reads points from the current position of the reader, with the same scaling as
+// `audio_to_coords`, and hands every point to `point`.
+fn read(
+    reader: &mut WavReader<Cursor<&[u8]>>,
+    points: usize,
+    mut point: impl FnMut(f32, f32),
+) -> Result<(), anyhow::Error> {
+    let spec = reader.spec();
+    let mut samples: Box<dyn Iterator<Item = hound::Result<f32>>> = match spec.sample_format {
+        SampleFormat::Int => {
+            let max_amplitude = 2f32.powi(spec.bits_per_sample as i32 - 1) - 1.0;
+            Box::new(
+                reader
+                    .samples::<i32>()
+                    .map(move |sample| sample.map(|s| s as f32 / max_amplitude)),
+            )
+        }
+        SampleFormat::Float => Box::new(reader.samples::<f32>()),
+    };
+    for _ in 0..points {
+        match (samples.next(), samples.next()) {
+            (Some(x), Some(y)) => point(x?, y?),
+            _ => break,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use crate::backend::settings::Settings;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// How a keyframe moves to the next keyframe.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Interpolation {
+    #[default]
+    Linear,
+    /// Holds the value until the next keyframe.
+    Step,
+    /// Eases in and out of the keyframes.
+    Eased,
+}
+
+/// A value at a point in time, in seconds.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Keyframe {
+    pub time: f64,
+    pub value: f64,
+    #[serde(default)]
+    pub interpolation: Interpolation,
+}
+
+/// Keyframes of a single setting. Before the first and after the last keyframe the curve holds
+/// the value of that keyframe.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Curve {
+    keyframes: Vec<Keyframe>,
+}
+
+impl Curve {
+    pub fn new(mut keyframes: Vec<Keyframe>) -> Curve {
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Curve { keyframes }
+    }
+
+    pub fn value_at(&self, time: f64) -> Option<f64> {
+        let first = self.keyframes.first()?;
+        if time <= first.time {
+            return Some(first.value);
+        }
+
+        let next = self.keyframes.partition_point(|k| k.time <= time);
+        let current = self.keyframes[next - 1];
+        let Some(next) = self.keyframes.get(next) else {
+            return Some(current.value);
+        };
+
+        let t = (time - current.time) / (next.time - current.time).max(f64::EPSILON);
+        let t = match current.interpolation {
+            Interpolation::Linear => t,
+            Interpolation::Step => 0.0,
+            Interpolation::Eased => t * t * (3.0 - 2.0 * t),
+        };
+        Some(current.value + (next.value - current.value) * t)
+    }
+}
+
+/// Curves by setting name, the names are the same camel case names the settings serialize to,
+/// so `{"threshold": [{"time": 0.0, "value": 0.0}, {"time": 2.0, "value": 255.0}]}` raises the
+/// threshold to its maximum in two seconds. The automation canvas converts its curves to this
+/// format before it sends them.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Automation {
+    curves: BTreeMap<String, Curve>,
+}
+
+// Display settings that only the front end reads, automating them would re-trace the image
+// without changing it.
+const FRONT_END_FIELDS: [&str; 4] = ["hue", "scale", "stroke", "persistence"];
+
+// Integer settings stored in a byte, every other integer setting fits a u32.
+const BYTE_FIELDS: [&str; 2] = ["threshold", "edgeThreshold"];
+
+impl Automation {
+    /// Every curve is sorted once here, so deserialized automation can be evaluated directly.
+    pub fn sorted(self) -> Automation {
+        Automation {
+            curves: self
+                .curves
+                .into_iter()
+                .map(|(name, curve)| (name, Curve::new(curve.keyframes)))
+                .collect(),
+        }
+    }
+
+    /// Whether there is no keyframe at all, automation without keyframes changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.curves.values().all(|curve| curve.keyframes.is_empty())
+    }
+
+    /// Whether any of the named settings has a curve with keyframes.
+    pub fn automates(&self, names: &[&str]) -> bool {
+        names.iter().any(|name| {
+            self.curves
+                .get(*name)
+                .is_some_and(|curve| !curve.keyframes.is_empty())
+        })
+    }
+
+    /// Settings with every automated field set to its value at the given time. Integer fields
+    /// are rounded and clamped to the range of their type and boolean fields are on from 0.5
+    /// upwards. Curves for front end settings and for settings that are not numbers or booleans
+    /// are ignored.
+    pub fn settings_at(&self, settings: &Settings, time: f64) -> Result<Settings, anyhow::Error> {
+        let mut value = serde_json::to_value(settings)?;
+        if let Value::Object(fields) = &mut value {
+            for (name, curve) in &self.curves {
+                if FRONT_END_FIELDS.contains(&name.as_str()) {
+                    continue;
+                }
+                let (Some(field), Some(v)) = (fields.get_mut(name), curve.value_at(time)) else {
+                    continue;
+                };
+                if !v.is_finite() {
+                    continue;
+                }
+                let max = match BYTE_FIELDS.contains(&name.as_str()) {
+                    true => u8::MAX as f64,
+                    false => u32::MAX as f64,
+                };
+                *field = match field {
+                    Value::Bool(_) => Value::from(v >= 0.5),
+                    Value::Number(n) if n.is_u64() => Value::from(v.round().clamp(0.0, max) as u64),
+                    Value::Number(n) if n.is_i64() => {
+                        Value::from(v.round().clamp(i32::MIN as f64, i32::MAX as f64) as i64)
+                    }
+                    Value::Number(_) => Value::from(v.clamp(f32::MIN as f64, f32::MAX as f64)),
+                    _ => continue,
+                };
+            }
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+}
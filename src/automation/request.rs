@@ -0,0 +1,70 @@
+use crate::automation::curve::Automation;
+use crate::backend::process_request::image_to_coords_request;
+use crate::backend::settings::Settings;
+use image::GrayImage;
+
+pub struct Request<Im, A>
+where
+    Im: std::ops::Deref<Target = GrayImage>,
+    A: std::ops::Deref<Target = Automation>,
+{
+    pub image: Im,
+    pub automation: A,
+    pub settings: Settings,
+    pub sample_rate: u32,
+    pub frame_rate: f32,
+    pub duration: f64,
+    pub result: Vec<(f32, f32)>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<Im, A> Request<Im, A>
+where
+    Im: std::ops::Deref<Target = GrayImage>,
+    A: std::ops::Deref<Target = Automation>,
+{
+    // The clip is cut into segments of one frame. Every segment evaluates the automation at its
+    // start and re-traces the image when the settings changed, then loops the trace until the
+    // segment is full. The loop keeps its position between segments, so held settings play back
+    // exactly like a static render.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let segment_samples = self.segment_samples();
+        let total = (self.duration.max(0.0) * self.sample_rate as f64).round() as usize;
+
+        let mut traced: Vec<(f32, f32)> = vec![];
+        let mut traced_with: Option<serde_json::Value> = None;
+        self.result = Vec::with_capacity(total);
+        let mut position = 0;
+
+        while self.result.len() < total {
+            let time = self.result.len() as f64 / self.sample_rate.max(1) as f64;
+            let settings = self.automation.settings_at(&self.settings, time)?;
+            let key = serde_json::to_value(&settings)?;
+            if traced_with.as_ref() != Some(&key) {
+                let mut request = image_to_coords_request(&settings, &*self.image);
+                request.process();
+                traced = request.result;
+                traced_with = Some(key);
+            }
+
+            let samples = segment_samples.min(total - self.result.len());
+            match traced.is_empty() {
+                true => self.result.extend(std::iter::repeat_n((0.0, 0.0), samples)),
+                false => {
+                    for _ in 0..samples {
+                        position %= traced.len();
+                        self.result.push(traced[position]);
+                        position += 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn segment_samples(&self) -> usize {
+        let frame_rate = self.frame_rate.max(f32::EPSILON);
+        ((self.sample_rate as f32 / frame_rate).round() as usize).max(1)
+    }
+}
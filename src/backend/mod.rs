@@ -88,7 +88,18 @@ impl Backend {
             ProcessRequest::ImageToCoords => processing::image_to_coords(self, args),
             ProcessRequest::AudioToCoords => processing::audio_to_coords(self, args),
             ProcessRequest::CoordsToAudio => processing::coords_to_audio(self, args),
+            ProcessRequest::MidiToAudio => processing::midi_to_audio(self, args),
+            ProcessRequest::ImageToSpectrogram => processing::image_to_spectrogram(self, args),
             ProcessRequest::ImageToBlackCoords => processing::image_to_black_coords(self, args),
+            ProcessRequest::SvgToCoords => processing::svg_to_coords(self, args),
+            ProcessRequest::FramesToCoords => processing::frames_to_coords(self, args),
+            ProcessRequest::ImageToAutomatedCoords => {
+                processing::image_to_automated_coords(self, args)
+            }
+            ProcessRequest::TextToCoords => processing::text_to_coords(self, args),
+            ProcessRequest::ShapeToCoords => processing::shape_to_coords(self, args),
+            ProcessRequest::MeshToCoords => processing::mesh_to_coords(self, args),
+            ProcessRequest::SceneToCoords => processing::scene_to_coords(self, args),
         }
     }
 }
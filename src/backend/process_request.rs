@@ -1,9 +1,30 @@
+use crate::analysis::request::{Analysis, Request as AnalysisRequest};
 use crate::audio_to_coords::request::Request as AudioToCoordsRequest;
+use crate::audio_to_spectrum::request::Request as AudioToSpectrumRequest;
+use crate::audio_to_window::request::Request as AudioToWindowRequest;
+use crate::automation::curve::Automation;
+use crate::automation::request::Request as AutomatedCoordsRequest;
 use crate::backend::settings::Settings;
 use crate::coords_to_audio::request::Request as CoordsToAudioRequest;
+use crate::coords_to_frames::request::Request as CoordsToFramesRequest;
+use crate::coords_to_vector::format::Format;
+use crate::coords_to_vector::request::Request as CoordsToVectorRequest;
+use crate::effects::request::Request as EffectsRequest;
+use crate::frames_to_coords::request::Request as FramesToCoordsRequest;
 use crate::image_to_coords::method::Method;
 use crate::image_to_coords::request::Request as ImageToCoordsRequest;
+use crate::image_to_spectrogram::request::Request as ImageToSpectrogramRequest;
+use crate::mesh_to_coords::request::Request as MeshToCoordsRequest;
+use crate::midi_to_coords::envelope::Envelope;
+use crate::midi_to_coords::request::Request as MidiToCoordsRequest;
+use crate::scene_to_coords::layer::Layer;
+use crate::scene_to_coords::request::Request as SceneToCoordsRequest;
+use crate::shape_to_coords::request::Request as ShapeToCoordsRequest;
+use crate::svg_to_coords::request::Request as SvgToCoordsRequest;
+use crate::text_to_coords::request::Request as TextToCoordsRequest;
+use crate::transform_coords::request::Request as TransformCoordsRequest;
 use image::GrayImage;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub enum ProcessRequest {
@@ -11,6 +32,15 @@ pub enum ProcessRequest {
     ImageToBlackCoords,
     AudioToCoords,
     CoordsToAudio,
+    MidiToAudio,
+    ImageToSpectrogram,
+    SvgToCoords,
+    FramesToCoords,
+    ImageToAutomatedCoords,
+    TextToCoords,
+    ShapeToCoords,
+    MeshToCoords,
+    SceneToCoords,
 }
 
 pub fn image_to_coords_request<Im>(settings: &Settings, img: Im) -> ImageToCoordsRequest<Im>
@@ -24,6 +54,7 @@ where
         pix_threshold: settings.pix_threshold,
         spread_type: settings.spread_type,
         result: Vec::new(),
+        contour_starts: Vec::new(),
         interpolate: settings.int_amount > 0,
         int_amount: settings.int_amount,
         size: settings.size,
@@ -39,6 +70,21 @@ where
         double_trace: settings.double_trace,
         edge_threshold: settings.edge_threshold,
         flatten: settings.flatten,
+        stipple_points: settings.stipple_points,
+        stipple_iterations: settings.stipple_iterations,
+        tsp_points: settings.tsp_points,
+        tsp_iterations: settings.tsp_iterations,
+        spiral_spacing: settings.spiral_spacing,
+        spiral_start_angle: settings.spiral_start_angle,
+        spiral_type: settings.spiral_type,
+        outer_only: settings.outer_only,
+        reverse_holes: settings.reverse_holes,
+        depth_first: settings.depth_first,
+        min_contour_length: settings.min_contour_length,
+        min_contour_area: settings.min_contour_area,
+        max_contours: settings.max_contours,
+        speckle_size: settings.speckle_size,
+        speckles: Vec::new(),
     }
 }
 
@@ -53,6 +99,7 @@ where
         pix_threshold: 0,
         spread_type: settings.spread_type,
         result: Vec::new(),
+        contour_starts: Vec::new(),
         interpolate: false,
         int_amount: 0,
         size: settings.size,
@@ -68,6 +115,21 @@ where
         double_trace: false,
         edge_threshold: settings.edge_threshold,
         flatten: false,
+        stipple_points: settings.stipple_points,
+        stipple_iterations: settings.stipple_iterations,
+        tsp_points: settings.tsp_points,
+        tsp_iterations: settings.tsp_iterations,
+        spiral_spacing: settings.spiral_spacing,
+        spiral_start_angle: settings.spiral_start_angle,
+        spiral_type: settings.spiral_type,
+        outer_only: false,
+        reverse_holes: false,
+        depth_first: false,
+        min_contour_length: 0,
+        min_contour_area: 0.0,
+        max_contours: 0,
+        speckle_size: 0,
+        speckles: Vec::new(),
     }
 }
 
@@ -83,6 +145,91 @@ where
     }
 }
 
+pub fn transform_coords_request<C, A>(
+    settings: &Settings,
+    coords: C,
+    automation: Option<A>,
+) -> TransformCoordsRequest<C, A>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+    A: std::ops::Deref<Target = Automation>,
+{
+    TransformCoordsRequest {
+        coords,
+        automation,
+        settings: settings.clone(),
+        sample_rate: settings.sample_rate,
+        frame_rate: settings.frame_rate,
+        duration: settings.clip_length,
+        result: Vec::new(),
+    }
+}
+
+pub fn effects_request<C>(settings: &Settings, coords: C) -> EffectsRequest<C>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+{
+    EffectsRequest {
+        coords,
+        sample_rate: settings.sample_rate,
+        remove_dc: settings.remove_dc,
+        high_pass: settings.high_pass,
+        low_pass: settings.low_pass,
+        ring_mod_frequency: settings.ring_mod_frequency,
+        ring_mod_amount: settings.ring_mod_amount,
+        stereo_rotation: settings.stereo_rotation,
+        noise_amount: settings.noise_amount,
+        noise_seed: settings.noise_seed,
+        soft_clip: settings.soft_clip,
+        duration: settings.clip_length,
+        result: Vec::new(),
+    }
+}
+
+pub fn midi_to_coords_request<C, M>(
+    settings: &Settings,
+    coords: C,
+    midi: M,
+) -> MidiToCoordsRequest<C, M>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+    M: std::ops::Deref<Target = Vec<u8>>,
+{
+    MidiToCoordsRequest {
+        coords,
+        midi,
+        sample_rate: settings.sample_rate,
+        envelope: Envelope {
+            attack: settings.synth_attack,
+            decay: settings.synth_decay,
+            sustain: settings.synth_sustain,
+            release: settings.synth_release,
+        },
+        result: Vec::new(),
+    }
+}
+
+pub fn coords_to_frames_request<C>(settings: &Settings, coords: C) -> CoordsToFramesRequest<C>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+{
+    CoordsToFramesRequest {
+        coords,
+        sample_rate: settings.sample_rate,
+        fps: settings.render_fps,
+        duration: settings.render_duration,
+        canvas_size: settings.canvas_size,
+        scale: settings.scale as f32,
+        center: (settings.center_x as f32, settings.center_y as f32),
+        stroke: settings.stroke as f32,
+        hue: settings.hue as f32,
+        persistence: settings.persistence as f32,
+        dot_mode: settings.dot_mode,
+        glow: settings.glow,
+        result: Vec::new(),
+    }
+}
+
 pub fn audio_to_coords_request<A>(audio: A) -> AudioToCoordsRequest<A>
 where
     A: std::ops::Deref<Target = Vec<u8>>,
@@ -90,5 +237,221 @@ where
     AudioToCoordsRequest {
         audio,
         result: Vec::new(),
+        sample_rate: 0,
+    }
+}
+
+pub fn analysis_request<C>(coords: C, sample_rate: u32, window_size: u32) -> AnalysisRequest<C>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+{
+    AnalysisRequest {
+        coords,
+        sample_rate,
+        window_size: window_size as usize,
+        result: Analysis::default(),
+    }
+}
+
+pub fn audio_to_window_request<A>(
+    audio: A,
+    start: f64,
+    end: f64,
+    max_points: u32,
+) -> AudioToWindowRequest<A>
+where
+    A: std::ops::Deref<Target = Vec<u8>>,
+{
+    AudioToWindowRequest {
+        audio,
+        start,
+        end,
+        max_points: max_points as usize,
+        result: Vec::new(),
+        sample_rate: 0,
+        frames: 0,
+    }
+}
+
+pub fn audio_to_spectrum_request<A>(audio: A, fft_size: u32, hop: u32) -> AudioToSpectrumRequest<A>
+where
+    A: std::ops::Deref<Target = Vec<u8>>,
+{
+    AudioToSpectrumRequest {
+        audio,
+        fft_size: fft_size as usize,
+        hop: hop as usize,
+        result: Vec::new(),
+    }
+}
+
+pub fn image_to_spectrogram_request<Im>(
+    settings: &Settings,
+    image: Im,
+) -> ImageToSpectrogramRequest<Im>
+where
+    Im: std::ops::Deref<Target = GrayImage>,
+{
+    ImageToSpectrogramRequest {
+        image,
+        sample_rate: settings.sample_rate,
+        fft_size: settings.spectrogram_fft_size as usize,
+        duration: settings.spectrogram_duration,
+        min_frequency: settings.spectrogram_min_frequency,
+        max_frequency: settings.spectrogram_max_frequency,
+        result: Vec::new(),
+    }
+}
+
+pub fn svg_to_coords_request<S>(settings: &Settings, svg: S) -> SvgToCoordsRequest<S>
+where
+    S: std::ops::Deref<Target = Vec<u8>>,
+{
+    SvgToCoordsRequest {
+        svg,
+        tolerance: settings.svg_tolerance,
+        interpolate: settings.int_amount > 0,
+        int_amount: settings.int_amount,
+        scramble: settings.scramble,
+        flatten: settings.flatten,
+        result: Vec::new(),
+        contour_starts: Vec::new(),
+    }
+}
+
+pub fn shape_to_coords_request(settings: &Settings) -> ShapeToCoordsRequest {
+    ShapeToCoordsRequest {
+        shape: settings.shape,
+        shape_points: settings.shape_points,
+        lissajous_ratio: settings.lissajous_ratio,
+        lissajous_phase: settings.lissajous_phase,
+        rose_ratio: settings.rose_ratio,
+        trochoid_radii: settings.trochoid_radii,
+        trochoid_offset: settings.trochoid_offset,
+        polygon_sides: settings.polygon_sides,
+        star_ratio: settings.star_ratio,
+        superformula_m: settings.superformula_m,
+        superformula_n: settings.superformula_n,
+        result: Vec::new(),
+    }
+}
+
+pub fn mesh_to_coords_request<O>(settings: &Settings, obj: Option<O>) -> MeshToCoordsRequest<O>
+where
+    O: std::ops::Deref<Target = Vec<u8>>,
+{
+    MeshToCoordsRequest {
+        wireframe: settings.wireframe,
+        obj,
+        rotation: settings.wireframe_rotation,
+        rotation_speed: settings.rotation_speed,
+        camera_distance: settings.camera_distance,
+        field_of_view: settings.field_of_view,
+        sample_rate: settings.sample_rate,
+        frame_rate: settings.frame_rate,
+        duration: settings.clip_length,
+        result: Vec::new(),
+    }
+}
+
+pub fn scene_to_coords_request<L>(
+    settings: &Settings,
+    layers: L,
+    images: Arc<Vec<Arc<GrayImage>>>,
+    font: Option<Arc<Vec<u8>>>,
+) -> SceneToCoordsRequest<L>
+where
+    L: std::ops::Deref<Target = Vec<Layer>>,
+{
+    SceneToCoordsRequest {
+        layers,
+        images,
+        font,
+        settings: settings.clone(),
+        scene_samples: settings.scene_samples,
+        result: Vec::new(),
+        contour_starts: Vec::new(),
+    }
+}
+
+pub fn text_to_coords_request<T>(
+    settings: &Settings,
+    text: T,
+    font: Option<Arc<Vec<u8>>>,
+) -> TextToCoordsRequest<T>
+where
+    T: std::ops::Deref<Target = String>,
+{
+    TextToCoordsRequest {
+        text,
+        font,
+        text_size: settings.text_size,
+        text_align: settings.text_align,
+        line_spacing: settings.line_spacing,
+        interpolate: settings.int_amount > 0,
+        int_amount: settings.int_amount,
+        flatten: settings.flatten,
+        result: Vec::new(),
+        contour_starts: Vec::new(),
+    }
+}
+
+pub fn frames_to_coords_request<F>(settings: &Settings, frames: F) -> FramesToCoordsRequest<F>
+where
+    F: std::ops::Deref<Target = Vec<GrayImage>>,
+{
+    FramesToCoordsRequest {
+        frames,
+        settings: settings.clone(),
+        sample_rate: settings.sample_rate,
+        frame_rate: settings.frame_rate,
+        frame_repeat: settings.frame_repeat,
+        coherent_frames: settings.coherent_frames,
+        result: Vec::new(),
+    }
+}
+
+pub fn image_to_automated_coords_request<Im, A>(
+    settings: &Settings,
+    img: Im,
+    automation: A,
+) -> AutomatedCoordsRequest<Im, A>
+where
+    Im: std::ops::Deref<Target = GrayImage>,
+    A: std::ops::Deref<Target = Automation>,
+{
+    AutomatedCoordsRequest {
+        image: img,
+        automation,
+        settings: settings.clone(),
+        sample_rate: settings.sample_rate,
+        frame_rate: settings.frame_rate,
+        duration: settings.clip_length,
+        result: Vec::new(),
+    }
+}
+
+pub fn coords_to_vector_request<C>(
+    settings: &Settings,
+    coords: C,
+    contour_starts: Vec<usize>,
+    format: Format,
+) -> CoordsToVectorRequest<C>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+{
+    CoordsToVectorRequest {
+        coords,
+        contour_starts,
+        format,
+        sample_rate: settings.sample_rate,
+        point_rate: settings.point_rate,
+        ilda_format: settings.ilda_format,
+        contour_colors: settings.contour_colors,
+        hue: settings.hue,
+        plot_size: settings.plot_size,
+        plot_origin: settings.plot_origin,
+        feed_rate: settings.feed_rate,
+        result: Vec::new(),
     }
 }
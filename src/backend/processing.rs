@@ -1,4 +1,8 @@
+use crate::automation::curve::Automation;
+use crate::backend::settings::Settings;
 use crate::backend::state;
+use crate::coords_to_audio::pitch;
+use image::GrayImage;
 use std::sync::Arc;
 
 use crate::backend::process_request::*;
@@ -35,6 +39,7 @@ pub fn image_to_coords(backend: &mut Backend, args: ProcessArgs) -> Result<()> {
         let mut request = image_to_coords_request(&settings, image.clone());
         request.process();
         let coords = Arc::new(request.result);
+        let contours = Arc::new(request.contour_starts);
         let request = state::SetCoords(coords);
         let (operation, receiver) = request.into_operation();
         if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
@@ -44,6 +49,14 @@ pub fn image_to_coords(backend: &mut Backend, args: ProcessArgs) -> Result<()> {
         if let Err(e) = receiver.await {
             tracing::error!("Error receiving response: {:?}", e);
         }
+        let (operation, receiver) = state::SetContours(contours).into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            tracing::error!("Failed to send image to coords contours: {:?}", e);
+            return;
+        }
+        if let Err(e) = receiver.await {
+            tracing::error!("Error receiving response: {:?}", e);
+        }
     });
     Ok(())
 }
@@ -81,15 +94,40 @@ pub fn coords_to_audio(backend: &mut Backend, args: ProcessArgs) -> Result<()> {
             "No coords set in state, cannot process audio."
         ));
     };
+    // Pitch lock squeezes the coords into a single cycle, which only makes sense for coords
+    // that repeat one drawing.
+    if backend.settings.pitch_lock && backend.state.is_animated() {
+        return Err(anyhow::anyhow!(
+            "Pitch lock only works on a single drawing, turn it off for animations and audio."
+        ));
+    }
 
+    let automation = backend.state.get_automation();
     let settings = backend.settings.clone();
+    let hidden = backend
+        .state
+        .get_image()
+        .filter(|_| settings.spectrogram_level > 0.0);
     let mut backend_tx = backend.self_tx.clone();
     wasm_bindgen_futures::spawn_local(async move {
         info!("Processing coords to audio with args: {:?}", args);
-        let mut request = coords_to_audio_request(&settings, coords);
+        let coords = match settings.pitch_lock {
+            true => Arc::new(pitch::lock_pitch(
+                &coords,
+                settings.sample_rate,
+                settings.pitch_frequency,
+            )),
+            false => coords,
+        };
+        let audio = match render_audio(&settings, coords, automation, hidden) {
+            Ok(audio) => audio,
+            Err(e) => {
+                tracing::error!("Failed to process coords to audio: {:?}", e);
+                return;
+            }
+        };
         info!("audio processing complete, sending audio to backend");
-        request.process();
-        let request = state::SetAudio(Arc::new(request.result));
+        let request = state::SetAudio(Arc::new(audio));
         let (operation, receiver) = request.into_operation();
         if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
             info!("Failed to send coords to audio processing result {:?}", e)
@@ -101,6 +139,80 @@ pub fn coords_to_audio(backend: &mut Backend, args: ProcessArgs) -> Result<()> {
     Ok(())
 }
 
+// The stages every exported signal goes through: the transforms, the effects, the hidden
+// spectrogram and the WAV encoding.
+fn render_audio(
+    settings: &Settings,
+    coords: Arc<Vec<(f32, f32)>>,
+    automation: Option<Arc<Automation>>,
+    hidden: Option<Arc<GrayImage>>,
+) -> Result<Vec<u8>> {
+    let mut request = transform_coords_request(settings, coords, automation);
+    request.process()?;
+    let mut request = effects_request(settings, Arc::new(request.result));
+    request.process();
+    let mut coords = request.result;
+    if let Some(image) = hidden {
+        // The image is spread over the spectrogram duration, a single trace is far too short to
+        // read it, so the coords are looped out to that length. It is mixed in quiet enough to
+        // leave the drawing intact.
+        let mut request = image_to_spectrogram_request(settings, image);
+        request.process()?;
+        let length = request.result.len().max(coords.len());
+        coords = coords.iter().copied().cycle().take(length).collect();
+        let level = settings.spectrogram_level;
+        for ((x, y), (l, r)) in coords.iter_mut().zip(request.result) {
+            (*x, *y) = (*x + l * level, *y + r * level);
+        }
+    }
+    let mut request = coords_to_audio_request(settings, Arc::new(coords));
+    request.process();
+    Ok(request.result)
+}
+
+pub fn midi_to_audio(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let Some(coords) = backend.state.get_coords() else {
+        return Err(anyhow::anyhow!("No coords set in state, cannot play midi."));
+    };
+    let Some(midi) = backend.state.get_midi() else {
+        return Err(anyhow::anyhow!("No midi set in state, cannot play midi."));
+    };
+    let automation = backend.state.get_automation();
+    let settings = backend.settings.clone();
+    let hidden = backend
+        .state
+        .get_image()
+        .filter(|_| settings.spectrogram_level > 0.0);
+    let mut request = midi_to_coords_request(&settings, coords, midi);
+    request.duration()?;
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!(
+            "processing midi with {} coords to audio",
+            request.coords.len()
+        );
+        if let Err(e) = request.process() {
+            tracing::error!("Failed to play midi: {:?}", e);
+            return;
+        }
+        let audio = match render_audio(&settings, Arc::new(request.result), automation, hidden) {
+            Ok(audio) => audio,
+            Err(e) => {
+                tracing::error!("Failed to process midi to audio: {:?}", e);
+                return;
+            }
+        };
+        let (operation, receiver) = state::SetAudio(Arc::new(audio)).into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send midi to audio processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
 pub fn audio_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
     let Some(audio) = backend.state.get_audio() else {
         return Err(anyhow::anyhow!(
@@ -112,7 +224,7 @@ pub fn audio_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()>
         info!("processing audio to coords");
         let mut request = audio_to_coords_request(audio);
         let _ = request.process();
-        let request = state::SetCoords(Arc::new(request.result));
+        let request = state::SetAnimation(Arc::new(request.result));
         let (operation, receiver) = request.into_operation();
         if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
             info!("Failed to send audio to coords processing result {:?}", e)
@@ -123,3 +235,235 @@ pub fn audio_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()>
     });
     Ok(())
 }
+
+pub fn svg_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let Some(svg) = backend.state.get_svg() else {
+        return Err(anyhow::anyhow!(
+            "No svg set in state, cannot process coords."
+        ));
+    };
+    let settings = backend.settings.clone();
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing svg to coords");
+        let mut request = svg_to_coords_request(&settings, svg);
+        if let Err(e) = request.process() {
+            tracing::error!("Failed to process svg: {:?}", e);
+            return;
+        }
+        let contours = Arc::new(request.contour_starts);
+        let request = state::SetCoords(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send svg to coords processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+        let (operation, receiver) = state::SetContours(contours).into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send svg to coords contours {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+pub fn frames_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let Some(frames) = backend.state.get_frames() else {
+        return Err(anyhow::anyhow!(
+            "No frames set in state, cannot process coords."
+        ));
+    };
+    let settings = backend.settings.clone();
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing {} frames to coords", frames.len());
+        let mut request = frames_to_coords_request(&settings, frames);
+        request.process();
+        let request = state::SetAnimation(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send frames to coords processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+pub fn image_to_automated_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let Some(image) = backend.state.get_image() else {
+        return Err(anyhow::anyhow!(
+            "No image set in state, cannot process coords."
+        ));
+    };
+    let automation = backend.state.get_automation().unwrap_or_default();
+    let settings = backend.settings.clone();
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing image to automated coords");
+        let mut request = image_to_automated_coords_request(&settings, image, automation);
+        if let Err(e) = request.process() {
+            tracing::error!("Failed to apply automation: {:?}", e);
+            return;
+        }
+        let request = state::SetAnimation(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send automated coords processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+pub fn text_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let Some(text) = backend.state.get_text() else {
+        return Err(anyhow::anyhow!(
+            "No text set in state, cannot process coords."
+        ));
+    };
+    let settings = backend.settings.clone();
+    let font = backend.state.get_font().filter(|_| settings.outline_font);
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing text to coords");
+        let mut request = text_to_coords_request(&settings, text, font);
+        if let Err(e) = request.process() {
+            tracing::error!("Failed to process text: {:?}", e);
+            return;
+        }
+        let contours = Arc::new(request.contour_starts);
+        let request = state::SetCoords(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send text to coords processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+        let (operation, receiver) = state::SetContours(contours).into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send text to coords contours {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+pub fn image_to_spectrogram(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let Some(image) = backend.state.get_image() else {
+        return Err(anyhow::anyhow!(
+            "No image set in state, cannot process spectrogram."
+        ));
+    };
+    let settings = backend.settings.clone();
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing image to spectrogram");
+        let mut request = image_to_spectrogram_request(&settings, image);
+        if let Err(e) = request.process() {
+            tracing::error!("Failed to process spectrogram: {:?}", e);
+            return;
+        }
+        let request = state::SetCoords(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!(
+                "Failed to send image to spectrogram processing result {:?}",
+                e
+            )
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+pub fn shape_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let settings = backend.settings.clone();
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing {} to coords", settings.shape);
+        let mut request = shape_to_coords_request(&settings);
+        request.process();
+        let request = state::SetCoords(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send shape to coords processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+pub fn mesh_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let mesh = backend.state.get_mesh();
+    let settings = backend.settings.clone();
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing {} wireframe to coords", settings.wireframe);
+        let mut request = mesh_to_coords_request(&settings, mesh);
+        if let Err(e) = request.process() {
+            tracing::error!("Failed to render wireframe: {:?}", e);
+            return;
+        }
+        let request = state::SetAnimation(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send mesh to coords processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+pub fn scene_to_coords(backend: &mut Backend, _args: ProcessArgs) -> Result<()> {
+    let Some(scene) = backend.state.get_scene() else {
+        return Err(anyhow::anyhow!(
+            "No scene set in state, cannot process coords."
+        ));
+    };
+    let images = backend.state.get_scene_images().unwrap_or_default();
+    let settings = backend.settings.clone();
+    let font = backend.state.get_font().filter(|_| settings.outline_font);
+    let mut backend_tx = backend.self_tx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        info!("processing scene with {} layers to coords", scene.len());
+        let mut request = scene_to_coords_request(&settings, scene, images, font);
+        if let Err(e) = request.process() {
+            tracing::error!("Failed to process scene: {:?}", e);
+            return;
+        }
+        let contours = Arc::new(request.contour_starts);
+        let request = state::SetCoords(Arc::new(request.result));
+        let (operation, receiver) = request.into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send scene to coords processing result {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+        let (operation, receiver) = state::SetContours(contours).into_operation();
+        if let Err(e) = backend_tx.send(backend::Request::State(operation)).await {
+            info!("Failed to send scene to coords contours {:?}", e)
+        }
+        if let Err(e) = receiver.await {
+            info!("Error receiving resonse: {:?}", e);
+        }
+    });
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use crate::image_to_coords::method::Method;
+use crate::mesh_to_coords::wireframe::Wireframe;
+use crate::shape_to_coords::shape::Shape;
 use crate::traits::{Operation, OperationTrait, RequestTrait};
 use anyhow::Result;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
     // Processing
@@ -26,6 +28,101 @@ pub struct Settings {
     pub scramble: bool,
     pub flatten: bool,
     pub double_trace: bool,
+    pub stipple_points: u32,
+    pub stipple_iterations: u32,
+    pub tsp_points: u32,
+    pub tsp_iterations: u32,
+    pub spiral_spacing: f32,
+    pub spiral_start_angle: f32,
+    pub spiral_type: u32,
+    pub outer_only: bool,
+    pub reverse_holes: bool,
+    pub depth_first: bool,
+    pub min_contour_length: u32,
+    pub min_contour_area: f32,
+    pub max_contours: u32,
+    pub speckle_size: u32,
+    pub svg_tolerance: f32,
+    // Shapes
+    pub shape: Shape,
+    pub shape_points: u32,
+    pub lissajous_ratio: (u32, u32),
+    pub lissajous_phase: f32,
+    pub rose_ratio: (u32, u32),
+    pub trochoid_radii: (u32, u32),
+    pub trochoid_offset: f32,
+    pub polygon_sides: u32,
+    pub star_ratio: f32,
+    pub superformula_m: u32,
+    pub superformula_n: (f32, f32, f32),
+    // Wireframe
+    pub wireframe: Wireframe,
+    pub wireframe_rotation: (f32, f32, f32),
+    pub rotation_speed: (f32, f32, f32),
+    pub camera_distance: f32,
+    pub field_of_view: f32,
+    // Transform
+    pub transform_rotate: f32,
+    pub transform_scale_x: f32,
+    pub transform_scale_y: f32,
+    pub transform_translate_x: f32,
+    pub transform_translate_y: f32,
+    pub transform_shear_x: f32,
+    pub transform_shear_y: f32,
+    pub mirror_x: bool,
+    pub mirror_y: bool,
+    pub fisheye: f32,
+    pub swirl: f32,
+    pub wave_amplitude: f32,
+    pub wave_frequency: f32,
+    pub transform_spin: f32,
+    pub wave_speed: f32,
+    // Pitch
+    pub pitch_lock: bool,
+    pub pitch_frequency: f32,
+    // Synth
+    pub synth_attack: f32,
+    pub synth_decay: f32,
+    pub synth_sustain: f32,
+    pub synth_release: f32,
+    // Spectrogram
+    pub spectrogram_fft_size: u32,
+    pub spectrogram_duration: f32,
+    pub spectrogram_min_frequency: f32,
+    pub spectrogram_max_frequency: f32,
+    pub spectrogram_level: f32,
+    // Effects
+    pub remove_dc: bool,
+    pub high_pass: f32,
+    pub low_pass: f32,
+    pub ring_mod_frequency: f32,
+    pub ring_mod_amount: f32,
+    pub stereo_rotation: f32,
+    pub noise_amount: f32,
+    pub noise_seed: u32,
+    pub soft_clip: f32,
+    // Scene
+    pub scene_samples: u32,
+    // Text
+    pub text_size: f32,
+    pub text_align: u32,
+    pub line_spacing: f32,
+    pub outline_font: bool,
+    // Export
+    pub point_rate: u32,
+    pub ilda_format: u32,
+    pub contour_colors: bool,
+    pub plot_size: f32,
+    pub plot_origin: (f32, f32),
+    pub feed_rate: f32,
+    // Rendering
+    pub render_fps: f32,
+    pub render_duration: f32,
+    pub glow: f32,
+    // Animation
+    pub frame_rate: f32,
+    pub frame_repeat: u32,
+    pub coherent_frames: bool,
     // Front end
     pub loop_audio: bool,
     pub repeat: u32,
@@ -63,6 +160,89 @@ impl Default for Settings {
             scramble: false,
             flatten: false,
             double_trace: false,
+            stipple_points: 2000,
+            stipple_iterations: 8,
+            tsp_points: 3000,
+            tsp_iterations: 4,
+            spiral_spacing: 10.0,
+            spiral_start_angle: 0.0,
+            spiral_type: 1,
+            outer_only: false,
+            reverse_holes: false,
+            depth_first: false,
+            min_contour_length: 2,
+            min_contour_area: 0.0,
+            max_contours: 0,
+            speckle_size: 0,
+            svg_tolerance: 0.5,
+            shape: Shape::Lissajous,
+            shape_points: 2000,
+            lissajous_ratio: (3, 2),
+            lissajous_phase: 90.0,
+            rose_ratio: (5, 1),
+            trochoid_radii: (5, 3),
+            trochoid_offset: 5.0,
+            polygon_sides: 5,
+            star_ratio: 1.0,
+            superformula_m: 6,
+            superformula_n: (1.0, 7.0, 8.0),
+            wireframe: Wireframe::Cube,
+            wireframe_rotation: (20.0, 0.0, 0.0),
+            rotation_speed: (0.0, 45.0, 0.0),
+            camera_distance: 3.0,
+            field_of_view: 50.0,
+            transform_rotate: 0.0,
+            transform_scale_x: 1.0,
+            transform_scale_y: 1.0,
+            transform_translate_x: 0.0,
+            transform_translate_y: 0.0,
+            transform_shear_x: 0.0,
+            transform_shear_y: 0.0,
+            mirror_x: false,
+            mirror_y: false,
+            fisheye: 0.0,
+            swirl: 0.0,
+            wave_amplitude: 0.0,
+            wave_frequency: 2.0,
+            transform_spin: 0.0,
+            wave_speed: 0.0,
+            pitch_lock: false,
+            pitch_frequency: 220.0,
+            synth_attack: 0.01,
+            synth_decay: 0.1,
+            synth_sustain: 0.8,
+            synth_release: 0.2,
+            spectrogram_fft_size: 2048,
+            spectrogram_duration: 5.0,
+            spectrogram_min_frequency: 200.0,
+            spectrogram_max_frequency: 16000.0,
+            spectrogram_level: 0.0,
+            remove_dc: false,
+            high_pass: 0.0,
+            low_pass: 0.0,
+            ring_mod_frequency: 0.0,
+            ring_mod_amount: 0.0,
+            stereo_rotation: 0.0,
+            noise_amount: 0.0,
+            noise_seed: 1,
+            soft_clip: 0.0,
+            scene_samples: 3000,
+            text_size: 0.3,
+            text_align: 1,
+            line_spacing: 1.2,
+            outline_font: false,
+            point_rate: 30000,
+            ilda_format: 5,
+            contour_colors: false,
+            plot_size: 200.0,
+            plot_origin: (0.0, 0.0),
+            feed_rate: 3000.0,
+            render_fps: 30.0,
+            render_duration: 0.0,
+            glow: 3.0,
+            frame_rate: 25.0,
+            frame_repeat: 1,
+            coherent_frames: true,
             size: 600,
             edge_detection: true,
             canvas_size: 600,
@@ -283,6 +463,89 @@ define_setting_requests! {
     scramble: bool,
     double_trace: bool,
     flatten: bool,
+    stipple_points: u32,
+    stipple_iterations: u32,
+    tsp_points: u32,
+    tsp_iterations: u32,
+    spiral_spacing: f32,
+    spiral_start_angle: f32,
+    spiral_type: u32,
+    outer_only: bool,
+    reverse_holes: bool,
+    depth_first: bool,
+    min_contour_length: u32,
+    min_contour_area: f32,
+    max_contours: u32,
+    speckle_size: u32,
+    svg_tolerance: f32,
+    shape: Shape,
+    shape_points: u32,
+    lissajous_ratio: (u32, u32),
+    lissajous_phase: f32,
+    rose_ratio: (u32, u32),
+    trochoid_radii: (u32, u32),
+    trochoid_offset: f32,
+    polygon_sides: u32,
+    star_ratio: f32,
+    superformula_m: u32,
+    superformula_n: (f32, f32, f32),
+    wireframe: Wireframe,
+    wireframe_rotation: (f32, f32, f32),
+    rotation_speed: (f32, f32, f32),
+    camera_distance: f32,
+    field_of_view: f32,
+    transform_rotate: f32,
+    transform_scale_x: f32,
+    transform_scale_y: f32,
+    transform_translate_x: f32,
+    transform_translate_y: f32,
+    transform_shear_x: f32,
+    transform_shear_y: f32,
+    mirror_x: bool,
+    mirror_y: bool,
+    fisheye: f32,
+    swirl: f32,
+    wave_amplitude: f32,
+    wave_frequency: f32,
+    transform_spin: f32,
+    wave_speed: f32,
+    pitch_lock: bool,
+    pitch_frequency: f32,
+    synth_attack: f32,
+    synth_decay: f32,
+    synth_sustain: f32,
+    synth_release: f32,
+    spectrogram_fft_size: u32,
+    spectrogram_duration: f32,
+    spectrogram_min_frequency: f32,
+    spectrogram_max_frequency: f32,
+    spectrogram_level: f32,
+    remove_dc: bool,
+    high_pass: f32,
+    low_pass: f32,
+    ring_mod_frequency: f32,
+    ring_mod_amount: f32,
+    stereo_rotation: f32,
+    noise_amount: f32,
+    noise_seed: u32,
+    soft_clip: f32,
+    scene_samples: u32,
+    text_size: f32,
+    text_align: u32,
+    line_spacing: f32,
+    outline_font: bool,
+    point_rate: u32,
+    ilda_format: u32,
+    contour_colors: bool,
+    plot_size: f32,
+    plot_origin: (f32, f32),
+    feed_rate: f32,
+    render_fps: f32,
+    render_duration: f32,
+    glow: f32,
+    frame_rate: f32,
+    frame_repeat: u32,
+    coherent_frames: bool,
 }
 
 impl Settings {
@@ -3,6 +3,8 @@ use std::sync::Arc;
 
 use image::GrayImage;
 
+use crate::automation::curve::Automation;
+use crate::scene_to_coords::layer::Layer;
 use crate::traits::{Operation, OperationTrait, RequestTrait};
 
 #[derive(Default, Debug)]
@@ -10,7 +12,19 @@ pub struct State {
     image: Option<Arc<GrayImage>>,
     audio: Option<Arc<Vec<u8>>>,
     coords: Option<Arc<Vec<(f32, f32)>>>,
+    // Coords that hold more than one drawing, like frame sequences, meshes and decoded audio.
+    animated: bool,
     black_coords: Option<Arc<Vec<(f32, f32)>>>,
+    svg: Option<Arc<Vec<u8>>>,
+    contours: Option<Arc<Vec<usize>>>,
+    frames: Option<Arc<Vec<GrayImage>>>,
+    automation: Option<Arc<Automation>>,
+    text: Option<Arc<String>>,
+    font: Option<Arc<Vec<u8>>>,
+    mesh: Option<Arc<Vec<u8>>>,
+    scene: Option<Arc<Vec<Layer>>>,
+    scene_images: Option<Arc<Vec<Arc<GrayImage>>>>,
+    midi: Option<Arc<Vec<u8>>>,
 }
 
 impl State {
@@ -44,12 +58,38 @@ impl State {
         self.coords.clone()
     }
 
+    /// NOTE: New coords invalidate the contour starts of the old ones, sources that know their
+    /// contours set them again after setting the coords.
     pub fn set_coords(&mut self, coords: Arc<Vec<(f32, f32)>>) -> Option<Arc<Vec<(f32, f32)>>> {
         let old_coords = self.coords.take();
         self.coords = Some(coords);
+        self.contours = None;
+        self.animated = false;
         old_coords
     }
 
+    /// Sets coords that change over time instead of repeating one drawing.
+    pub fn set_animation(&mut self, coords: Arc<Vec<(f32, f32)>>) -> Option<Arc<Vec<(f32, f32)>>> {
+        let old_coords = self.set_coords(coords);
+        self.animated = true;
+        old_coords
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.animated
+    }
+
+    /// Start index of every contour in the coords. `None` means the coords are one contour.
+    pub fn get_contours(&self) -> Option<Arc<Vec<usize>>> {
+        self.contours.clone()
+    }
+
+    pub fn set_contours(&mut self, contours: Arc<Vec<usize>>) -> Option<Arc<Vec<usize>>> {
+        let old_contours = self.contours.take();
+        self.contours = Some(contours);
+        old_contours
+    }
+
     pub fn get_black_coords(&mut self) -> Option<Arc<Vec<(f32, f32)>>> {
         self.black_coords.clone()
     }
@@ -63,6 +103,107 @@ impl State {
         old_black_coords
     }
 
+    pub fn get_svg(&self) -> Option<Arc<Vec<u8>>> {
+        self.svg.clone()
+    }
+
+    pub fn set_svg(&mut self, svg: Arc<Vec<u8>>) -> Option<Arc<Vec<u8>>> {
+        let old_svg = self.svg.take();
+        self.svg = Some(svg);
+        old_svg
+    }
+
+    pub fn get_frames(&self) -> Option<Arc<Vec<GrayImage>>> {
+        self.frames.clone()
+    }
+
+    pub fn set_frames(&mut self, frames: Arc<Vec<GrayImage>>) -> Option<Arc<Vec<GrayImage>>> {
+        let old_frames = self.frames.take();
+        self.frames = Some(frames);
+        old_frames
+    }
+
+    pub fn get_automation(&self) -> Option<Arc<Automation>> {
+        self.automation.clone()
+    }
+
+    /// `None` removes the automation.
+    pub fn set_automation(
+        &mut self,
+        automation: Option<Arc<Automation>>,
+    ) -> Option<Arc<Automation>> {
+        let old_automation = self.automation.take();
+        self.automation = automation;
+        old_automation
+    }
+
+    pub fn get_text(&self) -> Option<Arc<String>> {
+        self.text.clone()
+    }
+
+    pub fn set_text(&mut self, text: Arc<String>) -> Option<Arc<String>> {
+        let old_text = self.text.take();
+        self.text = Some(text);
+        old_text
+    }
+
+    pub fn get_font(&self) -> Option<Arc<Vec<u8>>> {
+        self.font.clone()
+    }
+
+    pub fn set_font(&mut self, font: Arc<Vec<u8>>) -> Option<Arc<Vec<u8>>> {
+        let old_font = self.font.take();
+        self.font = Some(font);
+        old_font
+    }
+
+    pub fn get_mesh(&self) -> Option<Arc<Vec<u8>>> {
+        self.mesh.clone()
+    }
+
+    pub fn set_mesh(&mut self, mesh: Arc<Vec<u8>>) -> Option<Arc<Vec<u8>>> {
+        let old_mesh = self.mesh.take();
+        self.mesh = Some(mesh);
+        old_mesh
+    }
+
+    pub fn get_midi(&self) -> Option<Arc<Vec<u8>>> {
+        self.midi.clone()
+    }
+
+    pub fn set_midi(&mut self, midi: Arc<Vec<u8>>) -> Option<Arc<Vec<u8>>> {
+        let old_midi = self.midi.take();
+        self.midi = Some(midi);
+        old_midi
+    }
+
+    pub fn get_scene(&self) -> Option<Arc<Vec<Layer>>> {
+        self.scene.clone()
+    }
+
+    pub fn set_scene(&mut self, scene: Arc<Vec<Layer>>) -> Option<Arc<Vec<Layer>>> {
+        let old_scene = self.scene.take();
+        self.scene = Some(scene);
+        old_scene
+    }
+
+    /// Images the image layers of a scene can trace. Without them the scene uses the image, which
+    /// is shared and not copied.
+    pub fn get_scene_images(&self) -> Option<Arc<Vec<Arc<GrayImage>>>> {
+        self.scene_images
+            .clone()
+            .or_else(|| self.image.clone().map(|image| Arc::new(vec![image])))
+    }
+
+    pub fn set_scene_images(
+        &mut self,
+        scene_images: Arc<Vec<Arc<GrayImage>>>,
+    ) -> Option<Arc<Vec<Arc<GrayImage>>>> {
+        let old_scene_images = self.scene_images.take();
+        self.scene_images = Some(scene_images);
+        old_scene_images
+    }
+
     pub async fn handle_request(
         &mut self,
         request: Box<dyn OperationTrait<State = Self>>,
@@ -209,6 +350,78 @@ impl RequestTrait for SetCoords {
     }
 }
 
+pub struct SetAnimation(pub Arc<Vec<(f32, f32)>>);
+
+impl RequestTrait for SetAnimation {
+    type State = State;
+    type Output = Option<Arc<Vec<(f32, f32)>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting animation");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| {
+                state.set_animation(self.0.clone());
+                state.coords.clone()
+            }),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct GetContours;
+
+impl RequestTrait for GetContours {
+    type State = State;
+    type Output = Option<Arc<Vec<usize>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("getting contours");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(|state: &mut State| state.get_contours()),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetContours(pub Arc<Vec<usize>>);
+
+impl RequestTrait for SetContours {
+    type State = State;
+    type Output = Option<Arc<Vec<usize>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting contours");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| {
+                state.set_contours(self.0.clone());
+                state.contours.clone()
+            }),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
 pub struct GetBlackCoords;
 
 impl RequestTrait for GetBlackCoords {
@@ -255,3 +468,248 @@ impl RequestTrait for SetBlackCoords {
         (Box::new(op), rx)
     }
 }
+
+pub struct SetSvg(pub Arc<Vec<u8>>);
+
+impl RequestTrait for SetSvg {
+    type State = State;
+    type Output = Option<Arc<Vec<u8>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting svg");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| {
+                state.set_svg(self.0.clone());
+                state.svg.clone()
+            }),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetFrames(pub Arc<Vec<GrayImage>>);
+
+impl RequestTrait for SetFrames {
+    type State = State;
+    type Output = Option<Arc<Vec<GrayImage>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting frames");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_frames(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct GetAutomation;
+
+impl RequestTrait for GetAutomation {
+    type State = State;
+    type Output = Option<Arc<Automation>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("getting automation");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(|state: &mut State| state.get_automation()),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetAutomation(pub Option<Arc<Automation>>);
+
+impl RequestTrait for SetAutomation {
+    type State = State;
+    type Output = Option<Arc<Automation>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting automation");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_automation(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetText(pub Arc<String>);
+
+impl RequestTrait for SetText {
+    type State = State;
+    type Output = Option<Arc<String>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting text");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_text(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetFont(pub Arc<Vec<u8>>);
+
+impl RequestTrait for SetFont {
+    type State = State;
+    type Output = Option<Arc<Vec<u8>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting font");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_font(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetMesh(pub Arc<Vec<u8>>);
+
+impl RequestTrait for SetMesh {
+    type State = State;
+    type Output = Option<Arc<Vec<u8>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting mesh");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_mesh(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct GetScene;
+
+impl RequestTrait for GetScene {
+    type State = State;
+    type Output = Option<Arc<Vec<Layer>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("getting scene");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(|state: &mut State| state.get_scene()),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetScene(pub Arc<Vec<Layer>>);
+
+impl RequestTrait for SetScene {
+    type State = State;
+    type Output = Option<Arc<Vec<Layer>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting scene");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_scene(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetSceneImages(pub Arc<Vec<Arc<GrayImage>>>);
+
+impl RequestTrait for SetSceneImages {
+    type State = State;
+    type Output = Option<Arc<Vec<Arc<GrayImage>>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting scene images");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_scene_images(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
+
+pub struct SetMidi(pub Arc<Vec<u8>>);
+
+impl RequestTrait for SetMidi {
+    type State = State;
+    type Output = Option<Arc<Vec<u8>>>;
+
+    fn into_operation(
+        self,
+    ) -> (
+        Box<dyn OperationTrait<State = Self::State>>,
+        futures::channel::oneshot::Receiver<Self::Output>,
+    ) {
+        log::info!("setting midi");
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let op = Operation {
+            handler: Box::new(move |state: &mut State| state.set_midi(self.0.clone())),
+            sender: tx,
+        };
+        (Box::new(op), rx)
+    }
+}
@@ -1 +1,2 @@
+pub mod pitch;
 pub mod request;
@@ -0,0 +1,49 @@
+// The loop may hold a few cycles when one cycle does not fit a whole amount of samples, the
+// pitch is then off by less than this part of a sample per cycle.
+const MAX_CYCLE_ERROR: f64 = 0.01;
+
+/// Frequency in Hz of a MIDI note, where note 69 is A4 at 440 Hz.
+pub fn note_to_frequency(note: u8) -> f32 {
+    440.0 * 2f32.powf((note as f32 - 69.0) / 12.0)
+}
+
+/// The MIDI note closest to a frequency in Hz.
+pub fn frequency_to_note(frequency: f32) -> u8 {
+    (69.0 + 12.0 * (frequency.max(f32::EPSILON) / 440.0).log2())
+        .round()
+        .clamp(0.0, 127.0) as u8
+}
+
+/// Resamples the traced path so it repeats at the given frequency, every cycle takes
+/// `sample_rate / frequency` samples. When that is not a whole number the path is sampled by
+/// phase over the least amount of cycles that loops cleanly, at most one second.
+pub fn lock_pitch(coords: &[(f32, f32)], sample_rate: u32, frequency: f32) -> Vec<(f32, f32)> {
+    if coords.is_empty() || frequency <= 0.0 {
+        return coords.to_vec();
+    }
+
+    let period = (sample_rate as f64 / frequency as f64).max(1.0);
+    let max_cycles = (frequency as f64).ceil().max(1.0) as usize;
+    let cycles = (1..=max_cycles)
+        .find(|&cycles| {
+            let samples = cycles as f64 * period;
+            (samples - samples.round()).abs() / cycles as f64 <= MAX_CYCLE_ERROR
+        })
+        .unwrap_or(max_cycles);
+    let samples = ((cycles as f64 * period).round() as usize).max(1);
+
+    (0..samples)
+        .map(|i| sample_at(coords, i as f64 * cycles as f64 / samples as f64))
+        .collect()
+}
+
+/// The point on the looping path at a phase, one cycle from 0.0 to 1.0 walks the whole path.
+pub fn sample_at(coords: &[(f32, f32)], phase: f64) -> (f32, f32) {
+    let len = coords.len();
+    let position = phase.rem_euclid(1.0) * len as f64;
+    let index = (position as usize).min(len - 1);
+    let t = (position - index as f64) as f32;
+    let (x0, y0) = coords[index];
+    let (x1, y1) = coords[(index + 1) % len];
+    (x0 + t * (x1 - x0), y0 + t * (y1 - y0))
+}
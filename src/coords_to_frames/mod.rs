@@ -0,0 +1,2 @@
+mod raster;
+pub mod request;
@@ -0,0 +1,117 @@
+use image::{ImageFormat, RgbImage};
+use std::io::Cursor;
+
+/// Beam energy the phosphor builds up per pixel, before it is turned into a color.
+pub struct Raster {
+    size: u32,
+    energy: Vec<f32>,
+}
+
+impl Raster {
+    pub fn new(size: u32) -> Raster {
+        Raster {
+            size,
+            energy: vec![0.0; (size * size) as usize],
+        }
+    }
+
+    /// Fades everything that was drawn, `remaining` is the part that is left.
+    pub fn decay(&mut self, remaining: f32) {
+        self.energy.iter_mut().for_each(|e| *e *= remaining);
+    }
+
+    /// Adds an anti-aliased line with the given width. The energy is spread over the length of
+    /// the line, so fast moves come out dimmer than slow ones, like a real beam.
+    pub fn line(&mut self, from: (f32, f32), to: (f32, f32), width: f32, energy: f32) {
+        let radius = width.max(1.0) / 2.0;
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let length_squared = dx * dx + dy * dy;
+        let intensity = energy / length_squared.sqrt().max(1.0);
+
+        let min_x = (from.0.min(to.0) - radius - 1.0).floor().max(0.0) as u32;
+        let min_y = (from.1.min(to.1) - radius - 1.0).floor().max(0.0) as u32;
+        let max_x = (from.0.max(to.0) + radius + 1.0)
+            .ceil()
+            .min(self.size as f32 - 1.0);
+        let max_y = (from.1.max(to.1) + radius + 1.0)
+            .ceil()
+            .min(self.size as f32 - 1.0);
+        if max_x < 0.0 || max_y < 0.0 {
+            return;
+        }
+
+        for y in min_y..=max_y as u32 {
+            for x in min_x..=max_x as u32 {
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+                let t = match length_squared > f32::EPSILON {
+                    true => {
+                        (((px - from.0) * dx + (py - from.1) * dy) / length_squared).clamp(0.0, 1.0)
+                    }
+                    false => 0.0,
+                };
+                let distance = (px - from.0 - t * dx).hypot(py - from.1 - t * dy);
+                let coverage = (radius + 0.5 - distance).clamp(0.0, 1.0);
+                if coverage > 0.0 {
+                    self.energy[(y * self.size + x) as usize] += intensity * coverage;
+                }
+            }
+        }
+    }
+
+    /// Light that spreads out around bright lines, three box blurs come close to a gaussian.
+    fn glow(&self, radius: f32) -> Vec<f32> {
+        let radius = radius.round() as usize;
+        let mut glow = self.energy.clone();
+        if radius == 0 {
+            return glow;
+        }
+        let size = self.size as usize;
+        let mut buffer = vec![0.0; glow.len()];
+        for _ in 0..3 {
+            box_blur(&glow, &mut buffer, size, radius, (1, size));
+            box_blur(&buffer, &mut glow, size, radius, (size, 1));
+        }
+        glow
+    }
+
+    /// Encodes the raster as a PNG, tinted with the color of the beam. Glow is added on top of
+    /// the sharp lines with the given strength.
+    pub fn to_png(
+        &self,
+        color: [f32; 3],
+        glow_radius: f32,
+        glow_strength: f32,
+    ) -> Result<Vec<u8>, anyhow::Error> {
+        let glow = self.glow(glow_radius);
+        let image = RgbImage::from_fn(self.size, self.size, |x, y| {
+            let i = (y * self.size + x) as usize;
+            let energy = self.energy[i] + glow[i] * glow_strength;
+            // The phosphor saturates towards the full color of the beam.
+            let level = 1.0 - (-energy).exp();
+            image::Rgb(color.map(|c| (c * level * 255.0).round() as u8))
+        });
+        let mut buffer = Cursor::new(Vec::new());
+        image.write_to(&mut buffer, ImageFormat::Png)?;
+        Ok(buffer.into_inner())
+    }
+}
+
+// Running average along rows or columns, the step is the distance between neighbours along the
+// blur and across it.
+fn box_blur(input: &[f32], output: &mut [f32], size: usize, radius: usize, step: (usize, usize)) {
+    let (along, across) = step;
+    let width = (2 * radius + 1) as f32;
+    for line in 0..size {
+        let at = |i: usize| input[line * across + i * along];
+        let mut sum: f32 = (0..=radius.min(size - 1)).map(at).sum();
+        for i in 0..size {
+            output[line * across + i * along] = sum / width;
+            if i + radius + 1 < size {
+                sum += at(i + radius + 1);
+            }
+            if i >= radius {
+                sum -= at(i - radius);
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::coords_to_frames::raster::Raster;
+
+// Energy a single sample leaves behind, spread over the line it draws.
+const BEAM_ENERGY: f32 = 1.5;
+const GLOW_STRENGTH: f32 = 0.8;
+
+pub struct Request<C: std::ops::Deref<Target = Vec<(f32, f32)>>> {
+    pub coords: C,
+    pub sample_rate: u32,
+    pub fps: f32,
+    /// Seconds to render, the coords loop when they are shorter. 0.0 renders the coords once.
+    pub duration: f32,
+    pub canvas_size: u32,
+    pub scale: f32,
+    pub center: (f32, f32),
+    pub stroke: f32,
+    pub hue: f32,
+    pub persistence: f32,
+    pub dot_mode: bool,
+    pub glow: f32,
+    /// PNG encoded frames.
+    pub result: Vec<Vec<u8>>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<C: std::ops::Deref<Target = Vec<(f32, f32)>>> Request<C> {
+    // Every frame fades what was drawn before by the persistence, like the canvas of the
+    // visualizer, then draws the samples that play during the frame. The beam continues from
+    // the last sample of the previous frame.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        if self.coords.is_empty() {
+            return Err(anyhow::anyhow!("There are no coords to render."));
+        }
+        let fps = self.fps.max(f32::EPSILON) as f64;
+        let samples_per_frame = self.sample_rate as f64 / fps;
+        let duration = match self.duration > 0.0 {
+            true => self.duration as f64,
+            false => self.coords.len() as f64 / self.sample_rate.max(1) as f64,
+        };
+        let frames = ((duration * fps).ceil() as usize).max(1);
+
+        let color = hsl_to_rgb(self.hue, 1.0, 0.4);
+        let remaining = (self.persistence / 100.0).clamp(0.0, 0.99);
+        let mut raster = Raster::new(self.canvas_size.max(1));
+        let mut last: Option<(f32, f32)> = None;
+
+        self.result = Vec::with_capacity(frames);
+        for frame in 0..frames {
+            raster.decay(remaining);
+            let first = (frame as f64 * samples_per_frame).round() as usize;
+            let end = ((frame + 1) as f64 * samples_per_frame).round() as usize;
+            for i in first..end {
+                let point = self.to_canvas(self.coords[i % self.coords.len()]);
+                match (self.dot_mode, last) {
+                    (true, _) | (false, None) => {
+                        raster.line(point, point, self.stroke * 2.0, BEAM_ENERGY)
+                    }
+                    (false, Some(last)) => raster.line(last, point, self.stroke, BEAM_ENERGY),
+                }
+                last = Some(point);
+            }
+            self.result
+                .push(raster.to_png(color, self.glow, GLOW_STRENGTH)?);
+        }
+        Ok(())
+    }
+
+    // Same mapping as the `Visualizer`.
+    fn to_canvas(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        (
+            self.center.0 + x * self.scale,
+            self.center.1 + y * self.scale,
+        )
+    }
+}
+
+// Hue in degrees, saturation and lightness from 0.0 to 1.0, like CSS `hsl()`.
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [f32; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    [r + m, g + m, b + m]
+}
@@ -0,0 +1,53 @@
+use serde::Serialize;
+use std::convert::TryFrom;
+
+/// Format enum
+/// Capture the different vector formats coords can be exported to
+#[derive(Default, Clone, Copy, Debug, Serialize)]
+pub enum Format {
+    #[default]
+    Svg,
+    Csv,
+    Json,
+    Ilda,
+    Gcode,
+    Hpgl,
+}
+
+/// Implement Display for Format
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", <&str>::from(*self))
+    }
+}
+
+/// Implement From<Format> for &str
+impl From<Format> for &str {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Svg => "svg",
+            Format::Csv => "csv",
+            Format::Json => "json",
+            Format::Ilda => "ilda",
+            Format::Gcode => "gcode",
+            Format::Hpgl => "hpgl",
+        }
+    }
+}
+
+/// Implement From<&str> for Format
+impl TryFrom<&str> for Format {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            _ if value.contains("svg") => Self::Svg,
+            _ if value.contains("csv") => Self::Csv,
+            _ if value.contains("json") => Self::Json,
+            _ if value.contains("ild") => Self::Ilda,
+            _ if value.contains("gcode") => Self::Gcode,
+            _ if value.contains("hpgl") => Self::Hpgl,
+            _ => return Err(()),
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use crate::coords_to_vector::request::Request;
+
+// Amount of blanked points the scanners get to travel between two contours.
+const BLANK_POINTS: usize = 4;
+
+// Hue distance between two contours when every contour gets its own colour.
+const GOLDEN_ANGLE: f64 = 137.508;
+
+// Largest amount of records a single ILDA frame can hold.
+const MAX_RECORDS: usize = u16::MAX as usize;
+
+struct Point {
+    x: i16,
+    y: i16,
+    blank: bool,
+    hue: f64,
+}
+
+fn to_rgb(hue: f64) -> (u8, u8, u8) {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = 1.0 - (h % 2.0 - 1.0).abs();
+    let (r, g, b) = match h as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    ((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
+}
+
+// The first 48 entries of the default ILDA palette run through the hue circle.
+fn to_palette_index(hue: f64) -> u8 {
+    ((hue.rem_euclid(360.0) / 360.0 * 48.0) as u8).min(47)
+}
+
+// ILDA uses signed 16 bit coordinates with y pointing upwards.
+fn to_ilda((x, y): (f32, f32)) -> (i16, i16) {
+    (
+        (x.clamp(-1.0, 1.0) * i16::MAX as f32) as i16,
+        (-y.clamp(-1.0, 1.0) * i16::MAX as f32) as i16,
+    )
+}
+
+impl<C: std::ops::Deref<Target = Vec<(f32, f32)>>> Request<C> {
+    // Resamples every contour to the laser point rate, so the frame refreshes as fast as the
+    // audio does, and inserts blanked points at the jumps between contours. A drawing that does
+    // not fit a single frame is an error, splitting it over frames would play it as an
+    // animation.
+    fn ilda_points(&self) -> Result<Vec<Point>, anyhow::Error> {
+        let rate = self.point_rate as f64 / self.sample_rate.max(1) as f64;
+        let mut points: Vec<Point> = vec![];
+
+        for (index, contour) in self.contours().iter().enumerate() {
+            let hue = match self.contour_colors {
+                true => self.hue + index as f64 * GOLDEN_ANGLE,
+                false => self.hue,
+            };
+            let amount = ((contour.len() as f64 * rate).round() as usize).max(1);
+            let resampled: Vec<(i16, i16)> = (0..amount)
+                .map(|i| to_ilda(contour[i * contour.len() / amount]))
+                .collect();
+
+            if let (Some(last), Some(&(x, y))) = (points.last(), resampled.first()) {
+                let (lx, ly) = (last.x, last.y);
+                for _ in 0..BLANK_POINTS {
+                    points.push(Point {
+                        x: lx,
+                        y: ly,
+                        blank: true,
+                        hue,
+                    });
+                }
+                for _ in 0..BLANK_POINTS {
+                    points.push(Point {
+                        x,
+                        y,
+                        blank: true,
+                        hue,
+                    });
+                }
+            }
+            for (x, y) in resampled {
+                points.push(Point {
+                    x,
+                    y,
+                    blank: false,
+                    hue,
+                });
+            }
+        }
+
+        if points.len() > MAX_RECORDS {
+            return Err(anyhow::anyhow!(
+                "The drawing needs {} ILDA points but a frame holds at most {}, lower the point rate.",
+                points.len(),
+                MAX_RECORDS
+            ));
+        }
+        Ok(points)
+    }
+
+    fn ilda_header(&self, format: u8, records: u16) -> Vec<u8> {
+        let mut header = Vec::with_capacity(32);
+        header.extend_from_slice(b"ILDA");
+        header.extend_from_slice(&[0, 0, 0, format]);
+        header.extend_from_slice(b"neo-osc\0"); // Frame name
+        header.extend_from_slice(b"metamaxo"); // Company name
+        header.extend_from_slice(&records.to_be_bytes());
+        header.extend_from_slice(&0u16.to_be_bytes()); // Frame number
+        header.extend_from_slice(&1u16.to_be_bytes()); // Total frames
+        header.extend_from_slice(&[0, 0]); // Projector number, reserved
+        header
+    }
+
+    // Writes the coords as a single ILDA frame in format 0 (3D indexed), 1 (2D indexed),
+    // 4 (3D true colour) or 5 (2D true colour), followed by the end of file header.
+    pub fn to_ilda(&self) -> Result<Vec<u8>, anyhow::Error> {
+        let format = match self.ilda_format {
+            0 | 1 | 4 => self.ilda_format as u8,
+            _ => 5,
+        };
+        let points = self.ilda_points()?;
+        let mut ilda = self.ilda_header(format, points.len() as u16);
+
+        for (i, point) in points.iter().enumerate() {
+            let mut status = 0u8;
+            if i == points.len() - 1 {
+                status |= 0b1000_0000;
+            }
+            if point.blank {
+                status |= 0b0100_0000;
+            }
+
+            ilda.extend_from_slice(&point.x.to_be_bytes());
+            ilda.extend_from_slice(&point.y.to_be_bytes());
+            if format == 0 || format == 4 {
+                ilda.extend_from_slice(&0i16.to_be_bytes());
+            }
+            ilda.push(status);
+            match format {
+                0 | 1 => ilda.push(to_palette_index(point.hue)),
+                _ if point.blank => ilda.extend_from_slice(&[0, 0, 0]),
+                _ => {
+                    let (r, g, b) = to_rgb(point.hue);
+                    ilda.extend_from_slice(&[b, g, r]);
+                }
+            }
+        }
+
+        ilda.extend(self.ilda_header(format, 0));
+        Ok(ilda)
+    }
+}
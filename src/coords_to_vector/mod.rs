@@ -0,0 +1,4 @@
+pub mod format;
+mod ilda;
+mod plotter;
+pub mod request;
@@ -0,0 +1,74 @@
+use crate::coords_to_vector::request::Request;
+use std::fmt::Write as _;
+
+// Pen heights in millimeters for G-code plotters that lift the pen with the Z axis.
+const PEN_UP_Z: f32 = 5.0;
+const PEN_DOWN_Z: f32 = 0.0;
+
+// HPGL plotter units per millimeter.
+const HPGL_UNITS_PER_MM: f32 = 40.0;
+
+impl<C: std::ops::Deref<Target = Vec<(f32, f32)>>> Request<C> {
+    // Maps coords to millimeters on the plotter bed. Plotters have y pointing upwards, so the
+    // drawing is flipped to keep it the same way up as on screen.
+    fn to_millimeters(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let size = self.plot_size;
+        (
+            self.plot_origin.0 + (x.clamp(-1.0, 1.0) + 1.0) / 2.0 * size,
+            self.plot_origin.1 + (1.0 - y.clamp(-1.0, 1.0)) / 2.0 * size,
+        )
+    }
+
+    // Every contour is one pen down stroke, travel between contours happens with the pen up.
+    pub fn to_gcode(&self) -> String {
+        let mut gcode = String::from("G21 ; millimeters\nG90 ; absolute positioning\n");
+        let _ = writeln!(gcode, "G0 Z{PEN_UP_Z:.3}");
+
+        for contour in self.contours() {
+            let Some((first, rest)) = contour.split_first() else {
+                continue;
+            };
+            let (x, y) = self.to_millimeters(*first);
+            let _ = writeln!(gcode, "G0 X{x:.3} Y{y:.3}");
+            let _ = writeln!(gcode, "G1 Z{PEN_DOWN_Z:.3} F{:.0}", self.feed_rate);
+            for point in rest {
+                let (x, y) = self.to_millimeters(*point);
+                let _ = writeln!(gcode, "G1 X{x:.3} Y{y:.3} F{:.0}", self.feed_rate);
+            }
+            let _ = writeln!(gcode, "G0 Z{PEN_UP_Z:.3}");
+        }
+
+        let (x, y) = self.plot_origin;
+        let _ = writeln!(gcode, "G0 X{x:.3} Y{y:.3}");
+        gcode
+    }
+
+    pub fn to_hpgl(&self) -> String {
+        // Velocity is set in centimeters per second.
+        let mut hpgl = format!("IN;SP1;VS{:.1};\n", self.feed_rate / 600.0);
+
+        for contour in self.contours() {
+            let units: Vec<(i32, i32)> = contour
+                .iter()
+                .map(|point| {
+                    let (x, y) = self.to_millimeters(*point);
+                    (
+                        (x * HPGL_UNITS_PER_MM).round() as i32,
+                        (y * HPGL_UNITS_PER_MM).round() as i32,
+                    )
+                })
+                .collect();
+            let Some(((x, y), rest)) = units.split_first() else {
+                continue;
+            };
+            let _ = writeln!(hpgl, "PU{x},{y};");
+            if !rest.is_empty() {
+                let points: Vec<String> = rest.iter().map(|(x, y)| format!("{x},{y}")).collect();
+                let _ = writeln!(hpgl, "PD{};", points.join(","));
+            }
+        }
+
+        hpgl.push_str("PU;SP0;\n");
+        hpgl
+    }
+}
@@ -0,0 +1,93 @@
+use crate::coords_to_vector::format::Format;
+use serde::Serialize;
+use std::fmt::Write as _;
+
+pub struct Request<C: std::ops::Deref<Target = Vec<(f32, f32)>>> {
+    pub coords: C,
+    pub contour_starts: Vec<usize>,
+    pub format: Format,
+    pub sample_rate: u32,
+    pub point_rate: u32,
+    pub ilda_format: u32,
+    pub contour_colors: bool,
+    pub hue: f64,
+    pub plot_size: f32,
+    pub plot_origin: (f32, f32),
+    pub feed_rate: f32,
+    pub result: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct JsonDocument<'a> {
+    contours: Vec<&'a [(f32, f32)]>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<C: std::ops::Deref<Target = Vec<(f32, f32)>>> Request<C> {
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        self.result = match self.format {
+            Format::Svg => self.to_svg().into_bytes(),
+            Format::Csv => self.to_csv().into_bytes(),
+            Format::Json => self.to_json()?,
+            Format::Ilda => self.to_ilda()?,
+            Format::Gcode => self.to_gcode().into_bytes(),
+            Format::Hpgl => self.to_hpgl().into_bytes(),
+        };
+        Ok(())
+    }
+
+    // Splits the coords back into the contours they were traced as. Without contour starts all
+    // coords are a single contour.
+    pub fn contours(&self) -> Vec<&[(f32, f32)]> {
+        let mut starts = self.contour_starts.clone();
+        if starts.first() != Some(&0) {
+            starts.insert(0, 0);
+        }
+        starts.push(self.coords.len());
+        starts
+            .windows(2)
+            .filter(|w| w[0] < w[1] && w[1] <= self.coords.len())
+            .map(|w| &self.coords[w[0]..w[1]])
+            .collect()
+    }
+
+    // Every contour becomes its own path in a [-1.0, 1.0] viewBox.
+    fn to_svg(&self) -> String {
+        let mut svg =
+            String::from("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-1 -1 2 2\">\n");
+        for contour in self.contours() {
+            let mut d = String::new();
+            for (i, (x, y)) in contour.iter().enumerate() {
+                let command = if i == 0 { 'M' } else { 'L' };
+                let _ = write!(d, "{command}{x:.5} {y:.5} ");
+            }
+            let _ = writeln!(
+                svg,
+                "  <path d=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+                d.trim_end()
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("contour,index,x,y\n");
+        for (contour_index, contour) in self.contours().iter().enumerate() {
+            for (i, (x, y)) in contour.iter().enumerate() {
+                let _ = writeln!(csv, "{contour_index},{i},{x},{y}");
+            }
+        }
+        csv
+    }
+
+    // Coords that are not finite come out as null, so the document always parses.
+    fn to_json(&self) -> Result<Vec<u8>, anyhow::Error> {
+        let mut json = serde_json::to_vec(&JsonDocument {
+            contours: self.contours(),
+        })?;
+        json.push(b'\n');
+        Ok(json)
+    }
+}
@@ -0,0 +1,63 @@
+use std::f32::consts::PI;
+
+/// Second order Butterworth filter, from the biquad formulas of the Audio EQ Cookbook.
+#[derive(Clone, Copy, Debug)]
+pub struct Biquad {
+    b: [f32; 3],
+    a: [f32; 2],
+    x: [f32; 2],
+    y: [f32; 2],
+}
+
+impl Biquad {
+    pub fn low_pass(cutoff: f32, sample_rate: u32) -> Biquad {
+        let (cos, alpha) = Self::coefficients(cutoff, sample_rate);
+        let b = (1.0 - cos) / 2.0;
+        Self::normalized([b, 1.0 - cos, b], cos, alpha)
+    }
+
+    pub fn high_pass(cutoff: f32, sample_rate: u32) -> Biquad {
+        let (cos, alpha) = Self::coefficients(cutoff, sample_rate);
+        let b = (1.0 + cos) / 2.0;
+        Self::normalized([b, -(1.0 + cos), b], cos, alpha)
+    }
+
+    // The cutoff is kept below nyquist, so the filter stays stable.
+    fn coefficients(cutoff: f32, sample_rate: u32) -> (f32, f32) {
+        let nyquist = sample_rate.max(1) as f32 / 2.0;
+        let cutoff = cutoff.clamp(1.0, nyquist * 0.99);
+        let omega = 2.0 * PI * cutoff / sample_rate.max(1) as f32;
+        let (sin, cos) = omega.sin_cos();
+        (cos, sin / (2.0 * std::f32::consts::FRAC_1_SQRT_2))
+    }
+
+    fn normalized(b: [f32; 3], cos: f32, alpha: f32) -> Biquad {
+        let a0 = 1.0 + alpha;
+        Biquad {
+            b: b.map(|b| b / a0),
+            a: [-2.0 * cos / a0, (1.0 - alpha) / a0],
+            x: [0.0; 2],
+            y: [0.0; 2],
+        }
+    }
+
+    pub fn next(&mut self, x: f32) -> f32 {
+        let y = self.b[0] * x + self.b[1] * self.x[0] + self.b[2] * self.x[1]
+            - self.a[0] * self.y[0]
+            - self.a[1] * self.y[1];
+        self.x = [x, self.x[0]];
+        self.y = [y, self.y[0]];
+        y
+    }
+
+    /// Filters a looping signal. The filter runs over the loop once before the output is kept,
+    /// so the start of the loop continues from its end instead of from silence.
+    pub fn process_loop(&mut self, signal: &mut [f32]) {
+        for &x in signal.iter() {
+            self.next(x);
+        }
+        for x in signal.iter_mut() {
+            *x = self.next(*x);
+        }
+    }
+}
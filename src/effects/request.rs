@@ -0,0 +1,107 @@
+use crate::effects::filter::Biquad;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f32::consts::TAU;
+
+pub struct Request<C: std::ops::Deref<Target = Vec<(f32, f32)>>> {
+    pub coords: C,
+    pub sample_rate: u32,
+    pub remove_dc: bool,
+    pub high_pass: f32,
+    pub low_pass: f32,
+    pub ring_mod_frequency: f32,
+    pub ring_mod_amount: f32,
+    pub stereo_rotation: f32,
+    pub noise_amount: f32,
+    pub noise_seed: u32,
+    pub soft_clip: f32,
+    pub duration: f64,
+    pub result: Vec<(f32, f32)>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<C: std::ops::Deref<Target = Vec<(f32, f32)>>> Request<C> {
+    // The effects run in a fixed order: DC removal, the filters, ring modulation, stereo
+    // rotation, noise and finally soft clipping, so the clipping also limits the noise. Effects
+    // that are turned off leave the signal untouched. Ring modulation and noise change over
+    // time, so they run over the coords looped out to the clip duration, and the carrier is
+    // rounded to whole cycles so it lines up when the player loops the clip.
+    pub fn process(&mut self) {
+        let length = match self.ring_mod_amount > 0.0 || self.noise_amount > 0.0 {
+            true => self.clip_samples().max(self.coords.len()),
+            false => self.coords.len(),
+        };
+        let (mut x, mut y): (Vec<f32>, Vec<f32>) =
+            self.coords.iter().copied().cycle().take(length).unzip();
+        let ring_mod_frequency = self.looping_frequency(length);
+
+        for channel in [&mut x, &mut y] {
+            if self.remove_dc {
+                Self::remove_dc(channel);
+            }
+            if self.high_pass > 0.0 {
+                Biquad::high_pass(self.high_pass, self.sample_rate).process_loop(channel);
+            }
+            if self.low_pass > 0.0 {
+                Biquad::low_pass(self.low_pass, self.sample_rate).process_loop(channel);
+            }
+        }
+
+        let (sin, cos) = self.stereo_rotation.to_radians().sin_cos();
+        let mut rng = StdRng::seed_from_u64(self.noise_seed as u64);
+        let sample_rate = self.sample_rate.max(1) as f32;
+        self.result = x
+            .into_iter()
+            .zip(y)
+            .enumerate()
+            .map(|(i, (x, y))| {
+                let (x, y) = match self.ring_mod_amount > 0.0 {
+                    true => {
+                        let carrier = (TAU * ring_mod_frequency * i as f32 / sample_rate).sin();
+                        let gain = 1.0 - self.ring_mod_amount + self.ring_mod_amount * carrier;
+                        (x * gain, y * gain)
+                    }
+                    false => (x, y),
+                };
+                let (x, y) = (x * cos - y * sin, x * sin + y * cos);
+                let (x, y) = match self.noise_amount > 0.0 {
+                    true => (
+                        x + rng.random_range(-1.0..=1.0) * self.noise_amount,
+                        y + rng.random_range(-1.0..=1.0) * self.noise_amount,
+                    ),
+                    false => (x, y),
+                };
+                (self.clip(x), self.clip(y))
+            })
+            .collect();
+    }
+
+    fn clip_samples(&self) -> usize {
+        (self.duration.max(0.0) * self.sample_rate as f64).round() as usize
+    }
+
+    // The nearest frequency that fits a whole number of cycles in the given amount of samples.
+    fn looping_frequency(&self, length: usize) -> f32 {
+        let seconds = length.max(1) as f32 / self.sample_rate.max(1) as f32;
+        let cycles = (self.ring_mod_frequency * seconds).round();
+        match self.ring_mod_frequency > 0.0 {
+            true => cycles.max(1.0) / seconds,
+            false => cycles / seconds,
+        }
+    }
+
+    fn remove_dc(channel: &mut [f32]) {
+        let mean = channel.iter().sum::<f32>() / channel.len().max(1) as f32;
+        channel.iter_mut().for_each(|x| *x -= mean);
+    }
+
+    // Tanh saturation scaled so full scale stays at full scale, the drive sets how early the
+    // signal starts to bend.
+    fn clip(&self, x: f32) -> f32 {
+        match self.soft_clip > 0.0 {
+            true => (x * self.soft_clip).tanh() / self.soft_clip.tanh(),
+            false => x,
+        }
+    }
+}
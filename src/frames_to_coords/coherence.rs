@@ -0,0 +1,189 @@
+use crate::frames_to_coords::request::Request;
+use crate::image_to_coords::processing_utils::resample_coords;
+use image::GrayImage;
+
+// Contours that moved or changed size more than this (in coords, where the canvas is 2.0 wide)
+// are treated as new contours instead of the continuation of an old one.
+const MAX_MATCH_COST: f32 = 0.5;
+
+// Amount of points compared when searching the best start point, longer contours are compared
+// with a stride to keep the search fast.
+const ALIGN_SAMPLES: usize = 64;
+
+pub type Contour = Vec<(f32, f32)>;
+
+fn length(contour: &[(f32, f32)]) -> f32 {
+    contour
+        .windows(2)
+        .map(|w| ((w[1].0 - w[0].0).powi(2) + (w[1].1 - w[0].1).powi(2)).sqrt())
+        .sum()
+}
+
+fn centroid(contour: &[(f32, f32)]) -> (f32, f32) {
+    let n = contour.len().max(1) as f32;
+    let (x, y) = contour
+        .iter()
+        .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+    (x / n, y / n)
+}
+
+// Splits coords into contours using the start index of every contour.
+pub fn split(coords: &[(f32, f32)], starts: &[usize]) -> Vec<Contour> {
+    let mut contours = vec![];
+    for (i, &start) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(coords.len());
+        if start < end && end <= coords.len() {
+            contours.push(coords[start..end].to_vec());
+        }
+    }
+    if contours.is_empty() && !coords.is_empty() {
+        contours.push(coords.to_vec());
+    }
+    contours
+}
+
+// Greedily pairs every current contour with the closest unused previous contour, closeness is
+// the distance between centroids plus the relative difference in length.
+pub fn match_contours(previous: &[Contour], current: &[Contour]) -> Vec<Option<usize>> {
+    let previous_shape: Vec<((f32, f32), f32)> =
+        previous.iter().map(|c| (centroid(c), length(c))).collect();
+    let current_shape: Vec<((f32, f32), f32)> =
+        current.iter().map(|c| (centroid(c), length(c))).collect();
+
+    let mut pairs = vec![];
+    for (i, ((cx, cy), cl)) in current_shape.iter().enumerate() {
+        for (j, ((px, py), pl)) in previous_shape.iter().enumerate() {
+            let distance = ((cx - px).powi(2) + (cy - py).powi(2)).sqrt();
+            let size = (cl - pl).abs() / (cl + pl).max(f32::EPSILON);
+            let cost = distance + size;
+            if cost <= MAX_MATCH_COST {
+                pairs.push((cost, i, j));
+            }
+        }
+    }
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut matches = vec![None; current.len()];
+    let mut used = vec![false; previous.len()];
+    for (_, i, j) in pairs {
+        if matches[i].is_none() && !used[j] {
+            matches[i] = Some(j);
+            used[j] = true;
+        }
+    }
+    matches
+}
+
+// Rotates the start point and picks the direction that keeps a contour closest to the contour
+// it continues. Both contours need the same amount of points.
+pub fn align(previous: &[(f32, f32)], mut current: Contour) -> Contour {
+    let n = current.len();
+    if n < 2 || previous.len() != n {
+        return current;
+    }
+    let stride = n.div_ceil(ALIGN_SAMPLES).max(1);
+
+    let cost = |contour: &[(f32, f32)], shift: usize| -> f32 {
+        (0..n)
+            .step_by(stride)
+            .map(|i| {
+                let (x, y) = contour[(i + shift) % n];
+                let (px, py) = previous[i];
+                (x - px).powi(2) + (y - py).powi(2)
+            })
+            .sum()
+    };
+    let best = |contour: &[(f32, f32)]| -> (usize, f32) {
+        (0..n)
+            .map(|shift| (shift, cost(contour, shift)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((0, 0.0))
+    };
+
+    let forward = best(&current);
+    let mut reversed = current.clone();
+    reversed.reverse();
+    let backward = best(&reversed);
+
+    let shift = match backward.1 < forward.1 {
+        true => {
+            current = reversed;
+            backward.0
+        }
+        false => forward.0,
+    };
+    current.rotate_left(shift);
+    current
+}
+
+// Splits the samples of a drawing over its contours. Contours that continue an old contour keep
+// its sample count, new contours get samples by length. The counts are scaled to fill the
+// drawing exactly.
+pub fn allocate(
+    current: &[Contour],
+    matches: &[Option<usize>],
+    previous_counts: &[usize],
+    samples: usize,
+) -> Vec<usize> {
+    let total_length: f32 = current
+        .iter()
+        .map(|c| length(c))
+        .sum::<f32>()
+        .max(f32::EPSILON);
+    let weights: Vec<f32> = current
+        .iter()
+        .zip(matches)
+        .map(|(contour, matched)| match matched {
+            Some(j) => previous_counts[*j] as f32,
+            None => length(contour) / total_length * samples as f32,
+        })
+        .map(|weight| weight.max(1.0))
+        .collect();
+
+    let scale = samples as f32 / weights.iter().sum::<f32>().max(f32::EPSILON);
+    let mut counts: Vec<usize> = weights.iter().map(|w| (w * scale) as usize).collect();
+
+    // Largest remainder rounding, so the counts add up to the samples of the drawing.
+    let mut remainders: Vec<(usize, f32)> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, w)| (i, w * scale - counts[i] as f32))
+        .collect();
+    remainders.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let missing = samples.saturating_sub(counts.iter().sum());
+    for (i, _) in remainders.iter().cycle().take(missing) {
+        counts[*i] += 1;
+    }
+    counts
+}
+
+impl<F: std::ops::Deref<Target = Vec<GrayImage>>> Request<F> {
+    // Turns the contours of a frame into a drawing that follows the previous drawing: matched
+    // contours keep their place in the drawing order, their sample count, their direction and
+    // their start point, new contours are drawn after them.
+    pub fn coherent_drawing(
+        &self,
+        previous: &[Contour],
+        current: Vec<Contour>,
+        samples: usize,
+    ) -> Vec<Contour> {
+        let matches = match_contours(previous, &current);
+        let previous_counts: Vec<usize> = previous.iter().map(|c| c.len()).collect();
+        let counts = allocate(&current, &matches, &previous_counts, samples);
+
+        let mut order: Vec<usize> = (0..current.len()).collect();
+        order.sort_by_key(|&i| matches[i].unwrap_or(usize::MAX));
+
+        order
+            .into_iter()
+            .filter(|&i| counts[i] > 0)
+            .map(|i| {
+                let contour = resample_coords(&current[i], counts[i]);
+                match matches[i] {
+                    Some(j) => align(&previous[j], contour),
+                    None => contour,
+                }
+            })
+            .collect()
+    }
+}
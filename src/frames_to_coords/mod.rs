@@ -0,0 +1,2 @@
+mod coherence;
+pub mod request;
@@ -0,0 +1,57 @@
+use crate::backend::process_request::image_to_coords_request;
+use crate::backend::settings::Settings;
+use crate::frames_to_coords::coherence::{self, Contour};
+use crate::image_to_coords::processing_utils::resample_coords;
+use image::GrayImage;
+
+pub struct Request<F: std::ops::Deref<Target = Vec<GrayImage>>> {
+    pub frames: F,
+    pub settings: Settings,
+    pub sample_rate: u32,
+    pub frame_rate: f32,
+    pub frame_repeat: u32,
+    pub coherent_frames: bool,
+    pub result: Vec<(f32, f32)>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<F: std::ops::Deref<Target = Vec<GrayImage>>> Request<F> {
+    pub fn process(&mut self) {
+        let samples_per_frame = self.samples_per_frame();
+        let repeat = self.frame_repeat.max(1) as usize;
+        let samples_per_drawing = (samples_per_frame / repeat).max(1);
+
+        self.result = Vec::with_capacity(samples_per_frame * self.frames.len());
+        let mut previous: Vec<Contour> = vec![];
+        for frame in self.frames.iter() {
+            let (traced, contour_starts) = self.trace(frame);
+            let drawing = match (traced.is_empty(), self.coherent_frames) {
+                (true, _) => vec![(0.0, 0.0); samples_per_drawing],
+                (false, false) => resample_coords(&traced, samples_per_drawing),
+                (false, true) => {
+                    let current = coherence::split(&traced, &contour_starts);
+                    previous = self.coherent_drawing(&previous, current, samples_per_drawing);
+                    previous.concat()
+                }
+            };
+
+            for _ in 0..repeat {
+                self.result.extend_from_slice(&drawing);
+            }
+        }
+    }
+
+    // Every frame lasts exactly one frame period, no matter how many coords it traced to.
+    pub fn samples_per_frame(&self) -> usize {
+        let frame_rate = self.frame_rate.max(f32::EPSILON);
+        ((self.sample_rate as f32 / frame_rate).round() as usize).max(1)
+    }
+
+    // Frames are traced with the same method and settings as a single image.
+    fn trace(&self, frame: &GrayImage) -> (Vec<(f32, f32)>, Vec<usize>) {
+        let mut request = image_to_coords_request(&self.settings, frame);
+        request.process();
+        (request.result, request.contour_starts)
+    }
+}
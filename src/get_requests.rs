@@ -1,6 +1,12 @@
 use crate::JsInterface;
+use crate::backend::process_request::{
+    analysis_request, audio_to_coords_request, audio_to_spectrum_request, audio_to_window_request,
+    coords_to_frames_request, coords_to_vector_request,
+};
 use crate::backend::settings::*;
 use crate::backend::state;
+use crate::coords_to_audio::pitch;
+use crate::coords_to_vector::format::Format;
 use crate::to_js;
 use serde_wasm_bindgen::to_value;
 
@@ -122,6 +128,689 @@ impl JsInterface {
         ))
     }
 
+    #[wasm_bindgen]
+    pub async fn get_stipple_points(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetStipplePoints).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_stipple_iterations(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetStippleIterations)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_tsp_points(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetTspPoints).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_tsp_iterations(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetTspIterations).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spiral_spacing(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSpiralSpacing).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spiral_start_angle(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetSpiralStartAngle)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spiral_type(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSpiralType).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_outer_only(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetOuterOnly).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_reverse_holes(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetReverseHoles).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_depth_first(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetDepthFirst).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_min_contour_length(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetMinContourLength)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_min_contour_area(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetMinContourArea)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_max_contours(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetMaxContours).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_speckle_size(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSpeckleSize).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_svg_tolerance(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSvgTolerance).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_point_rate(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetPointRate).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_ilda_format(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetIldaFormat).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_contour_colors(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetContourColors).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_plot_size(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetPlotSize).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_plot_origin(&mut self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.settings(GetPlotOrigin).await.map_err(to_js)?).unwrap())
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_feed_rate(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetFeedRate).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_frame_rate(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetFrameRate).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_frame_repeat(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetFrameRepeat).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_coherent_frames(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetCoherentFrames)
+                .await
+                .map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_automation(&mut self) -> Result<JsValue, JsValue> {
+        let automation = self
+            .inner
+            .state(state::GetAutomation)
+            .await
+            .map_err(to_js)?
+            .unwrap_or_default();
+        to_value(&*automation).map_err(to_js)
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_text_size(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetTextSize).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_text_align(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetTextAlign).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_line_spacing(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetLineSpacing).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_outline_font(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetOutlineFont).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_shape(&mut self) -> Result<JsValue, JsValue> {
+        let shape = self.inner.settings(GetShape).await.map_err(to_js)?;
+        Ok(JsValue::from_str(shape.into()))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_shape_points(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetShapePoints).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_lissajous_ratio(&mut self) -> Result<JsValue, JsValue> {
+        Ok(to_value(
+            &self
+                .inner
+                .settings(GetLissajousRatio)
+                .await
+                .map_err(to_js)?,
+        )
+        .unwrap())
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_lissajous_phase(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetLissajousPhase)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_rose_ratio(&mut self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.settings(GetRoseRatio).await.map_err(to_js)?).unwrap())
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_trochoid_radii(&mut self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.settings(GetTrochoidRadii).await.map_err(to_js)?).unwrap())
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_trochoid_offset(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTrochoidOffset)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_polygon_sides(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetPolygonSides).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_star_ratio(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetStarRatio).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_superformula_m(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSuperformulaM).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_superformula_n(&mut self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.settings(GetSuperformulaN).await.map_err(to_js)?).unwrap())
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_wireframe(&mut self) -> Result<JsValue, JsValue> {
+        let wireframe = self.inner.settings(GetWireframe).await.map_err(to_js)?;
+        Ok(JsValue::from_str(wireframe.into()))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_wireframe_rotation(&mut self) -> Result<JsValue, JsValue> {
+        Ok(to_value(
+            &self
+                .inner
+                .settings(GetWireframeRotation)
+                .await
+                .map_err(to_js)?,
+        )
+        .unwrap())
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_rotation_speed(&mut self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.settings(GetRotationSpeed).await.map_err(to_js)?).unwrap())
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_camera_distance(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetCameraDistance)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_field_of_view(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetFieldOfView).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_scene_samples(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSceneSamples).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_scene(&mut self) -> Result<JsValue, JsValue> {
+        let scene = self
+            .inner
+            .state(state::GetScene)
+            .await
+            .map_err(to_js)?
+            .unwrap_or_default();
+        to_value(&*scene).map_err(to_js)
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_rotate(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTransformRotate)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_scale_x(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTransformScaleX)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_scale_y(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTransformScaleY)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_translate_x(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTransformTranslateX)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_translate_y(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTransformTranslateY)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_shear_x(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTransformShearX)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_shear_y(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetTransformShearY)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_mirror_x(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetMirrorX).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_mirror_y(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetMirrorY).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_fisheye(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetFisheye).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_swirl(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSwirl).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_wave_amplitude(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetWaveAmplitude).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_wave_frequency(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetWaveFrequency).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_transform_spin(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetTransformSpin).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_wave_speed(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetWaveSpeed).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_remove_dc(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetRemoveDc).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_high_pass(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetHighPass).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_low_pass(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetLowPass).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_ring_mod_frequency(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetRingModFrequency)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_ring_mod_amount(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetRingModAmount).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_stereo_rotation(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetStereoRotation)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_noise_amount(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetNoiseAmount).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_noise_seed(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetNoiseSeed).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_soft_clip(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSoftClip).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_pitch_lock(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetPitchLock).await.map_err(to_js)?,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_pitch_frequency(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetPitchFrequency)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_pitch_note(&mut self) -> Result<JsValue, JsValue> {
+        let frequency = self
+            .inner
+            .settings(GetPitchFrequency)
+            .await
+            .map_err(to_js)?;
+        Ok(JsValue::from(pitch::frequency_to_note(frequency)))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_synth_attack(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSynthAttack).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_synth_decay(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSynthDecay).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_synth_sustain(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSynthSustain).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_synth_release(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetSynthRelease).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spectrogram_fft_size(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetSpectrogramFftSize)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spectrogram_duration(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetSpectrogramDuration)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spectrogram_min_frequency(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetSpectrogramMinFrequency)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spectrogram_max_frequency(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetSpectrogramMaxFrequency)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_spectrogram_level(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetSpectrogramLevel)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_render_fps(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetRenderFps).await.map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_render_duration(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner
+                .settings(GetRenderDuration)
+                .await
+                .map_err(to_js)? as f64,
+        ))
+    }
+
+    #[wasm_bindgen]
+    pub async fn get_glow(&mut self) -> Result<JsValue, JsValue> {
+        Ok(JsValue::from(
+            self.inner.settings(GetGlow).await.map_err(to_js)? as f64,
+        ))
+    }
+
     #[wasm_bindgen]
     pub async fn get_settings_json(&mut self) -> Result<JsValue, JsValue> {
         Ok(to_value(&self.inner.settings(GetSettings).await.map_err(to_js)?).unwrap())
@@ -153,4 +842,120 @@ impl JsInterface {
             Err(e) => Err(to_js(e)),
         }
     }
+
+    // Interleaved x and y of the audio from `start` up to `end` in seconds, at most `max_points`
+    // points, 0 returns every point. Seeking only decodes the requested window.
+    #[wasm_bindgen]
+    pub async fn get_audio_window(
+        &mut self,
+        start: f64,
+        end: f64,
+        max_points: u32,
+    ) -> Result<js_sys::Float32Array, JsValue> {
+        let Some(audio) = self.inner.state(state::GetAudio).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Audio data is missing")));
+        };
+        let mut request = audio_to_window_request(audio, start, end, max_points);
+        request.process().map_err(to_js)?;
+        Ok(js_sys::Float32Array::from(request.result.as_slice()))
+    }
+
+    // Length of the audio in seconds, without decoding it.
+    #[wasm_bindgen]
+    pub async fn get_audio_duration(&mut self) -> Result<JsValue, JsValue> {
+        let Some(audio) = self.inner.state(state::GetAudio).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Audio data is missing")));
+        };
+        let mut request = audio_to_window_request(audio, 0.0, 0.0, 0);
+        request.process().map_err(to_js)?;
+        Ok(JsValue::from(
+            request.frames as f64 / request.sample_rate.max(1) as f64,
+        ))
+    }
+
+    // Sample rate of the audio, the rate of the points `get_audio_window` returns.
+    #[wasm_bindgen]
+    pub async fn get_audio_sample_rate(&mut self) -> Result<u32, JsValue> {
+        let Some(audio) = self.inner.state(state::GetAudio).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Audio data is missing")));
+        };
+        let mut request = audio_to_window_request(audio, 0.0, 0.0, 0);
+        request.process().map_err(to_js)?;
+        Ok(request.sample_rate)
+    }
+
+    // Levels, stereo correlation, fundamental and frame boundaries of the audio, analysed in
+    // windows of `window_size` samples.
+    #[wasm_bindgen]
+    pub async fn get_analysis(&mut self, window_size: u32) -> Result<JsValue, JsValue> {
+        let Some(audio) = self.inner.state(state::GetAudio).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Audio data is missing")));
+        };
+        let mut request = audio_to_coords_request(audio);
+        request.process().map_err(to_js)?;
+        let mut request = analysis_request(&request.result, request.sample_rate, window_size);
+        request.process();
+        to_value(&request.result).map_err(to_js)
+    }
+
+    // Averaged magnitudes in dB of the audio, `fft_size / 2 + 1` bins from 0 Hz up to nyquist.
+    #[wasm_bindgen]
+    pub async fn get_spectrum(&mut self, fft_size: u32) -> Result<js_sys::Float32Array, JsValue> {
+        let Some(audio) = self.inner.state(state::GetAudio).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Audio data is missing")));
+        };
+        let mut request = audio_to_spectrum_request(audio, fft_size, fft_size / 2);
+        request.process().map_err(to_js)?;
+        Ok(js_sys::Float32Array::from(request.average().as_slice()))
+    }
+
+    // Magnitudes in dB of every frame after each other, every frame holds `fft_size / 2 + 1`
+    // bins.
+    #[wasm_bindgen]
+    pub async fn get_spectrogram(
+        &mut self,
+        fft_size: u32,
+        hop: u32,
+    ) -> Result<js_sys::Float32Array, JsValue> {
+        let Some(audio) = self.inner.state(state::GetAudio).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Audio data is missing")));
+        };
+        let mut request = audio_to_spectrum_request(audio, fft_size, hop);
+        request.process().map_err(to_js)?;
+        let flat: Vec<f32> = request.result.into_iter().flatten().collect();
+        Ok(js_sys::Float32Array::from(flat.as_slice()))
+    }
+
+    // PNG frames of the coords drawn like the visualizer, one for every frame at the render fps.
+    #[wasm_bindgen]
+    pub async fn render_frames(&mut self) -> Result<js_sys::Array, JsValue> {
+        info!("rendering frames");
+        let Some(coords) = self.inner.state(state::GetCoords).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Coords data is missing")));
+        };
+        let settings = self.inner.settings(GetSettings).await.map_err(to_js)?;
+        let mut request = coords_to_frames_request(&settings, coords);
+        request.process().map_err(to_js)?;
+        Ok(request
+            .result
+            .iter()
+            .map(|frame| js_sys::Uint8Array::from(frame.as_slice()))
+            .collect())
+    }
+
+    #[wasm_bindgen]
+    pub async fn export_coords(&mut self, format: &str) -> Result<js_sys::Uint8Array, JsValue> {
+        info!("exporting coords as {}", format);
+        let format = Format::try_from(format).map_err(|_| JsValue::from_str("Unknown format"))?;
+        let Some(coords) = self.inner.state(state::GetCoords).await.map_err(to_js)? else {
+            return Err(to_js(anyhow::anyhow!("Coords data is missing")));
+        };
+        let contours = self.inner.state(state::GetContours).await.map_err(to_js)?;
+        let settings = self.inner.settings(GetSettings).await.map_err(to_js)?;
+
+        let contour_starts = contours.map(|c| c.to_vec()).unwrap_or_default();
+        let mut request = coords_to_vector_request(&settings, coords, contour_starts, format);
+        request.process().map_err(to_js)?;
+        Ok(js_sys::Uint8Array::from(request.result.as_slice()))
+    }
 }
@@ -0,0 +1,179 @@
+use crate::image_to_coords::request::Request;
+use image::GrayImage;
+use std::collections::HashSet;
+
+/// A traced contour together with its place in the contour hierarchy.
+#[derive(Debug, Clone)]
+pub struct Contour {
+    pub points: Vec<(u32, u32)>,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+    pub depth: usize,
+    /// Holes are contours that sit inside an odd amount of other contours.
+    pub hole: bool,
+    /// Winding direction on screen, where y points downwards.
+    pub clockwise: bool,
+    area: f64,
+    bounds: (u32, u32, u32, u32),
+}
+
+impl Contour {
+    fn new(points: Vec<(u32, u32)>) -> Contour {
+        let area = signed_area(&points);
+        let bounds = bounds(&points);
+        Contour {
+            points,
+            parent: None,
+            children: vec![],
+            depth: 0,
+            hole: false,
+            clockwise: area > 0.0,
+            area: area.abs(),
+            bounds,
+        }
+    }
+
+    fn contains_bounds(&self, other: &Contour) -> bool {
+        let (min_x, min_y, max_x, max_y) = self.bounds;
+        let (o_min_x, o_min_y, o_max_x, o_max_y) = other.bounds;
+        min_x <= o_min_x && min_y <= o_min_y && max_x >= o_max_x && max_y >= o_max_y
+    }
+
+    // Even-odd ray casting, tested at the center of the pixel.
+    fn contains_point(&self, point: (u32, u32)) -> bool {
+        let (px, py) = (point.0 as f64 + 0.5, point.1 as f64 + 0.5);
+        let mut inside = false;
+        let n = self.points.len();
+        for i in 0..n {
+            let (x0, y0) = self.points[i];
+            let (x1, y1) = self.points[(i + 1) % n];
+            let (x0, y0, x1, y1) = (x0 as f64, y0 as f64, x1 as f64, y1 as f64);
+            if (y0 > py) != (y1 > py) && px < x0 + (py - y0) / (y1 - y0) * (x1 - x0) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    fn reverse(&mut self) {
+        self.points.reverse();
+        self.clockwise = !self.clockwise;
+    }
+}
+
+/// Shoelace formula, positive for clockwise contours on screen.
+pub fn signed_area(points: &[(u32, u32)]) -> f64 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (x0, y0) = points[i];
+            let (x1, y1) = points[(i + 1) % n];
+            x0 as f64 * y1 as f64 - x1 as f64 * y0 as f64
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+fn bounds(points: &[(u32, u32)]) -> (u32, u32, u32, u32) {
+    points.iter().fold(
+        (u32::MAX, u32::MAX, 0, 0),
+        |(min_x, min_y, max_x, max_y), &(x, y)| {
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+        },
+    )
+}
+
+/// Builds the parent/child relations between contours. The parent of a contour is the smallest
+/// contour that encloses it. Empty contours are kept in place but never get a parent.
+pub fn build_hierarchy(contours: Vec<Vec<(u32, u32)>>) -> Vec<Contour> {
+    let mut tree: Vec<Contour> = contours.into_iter().map(Contour::new).collect();
+    // A point on a contour is not inside it, these sets make that check constant time.
+    let on_contour: Vec<HashSet<(u32, u32)>> = tree
+        .iter()
+        .map(|contour| contour.points.iter().copied().collect())
+        .collect();
+
+    for i in 0..tree.len() {
+        let Some(&probe) = tree[i].points.first() else {
+            continue;
+        };
+        let parent = (0..tree.len())
+            .filter(|&j| {
+                j != i
+                    && tree[j].area > tree[i].area
+                    && tree[j].contains_bounds(&tree[i])
+                    && !on_contour[j].contains(&probe)
+                    && tree[j].contains_point(probe)
+            })
+            .min_by(|&a, &b| tree[a].area.total_cmp(&tree[b].area));
+        tree[i].parent = parent;
+        if let Some(parent) = parent {
+            tree[parent].children.push(i);
+        }
+    }
+
+    for i in 0..tree.len() {
+        let mut depth = 0;
+        let mut current = tree[i].parent;
+        while let Some(parent) = current {
+            depth += 1;
+            current = tree[parent].parent;
+        }
+        tree[i].depth = depth;
+        tree[i].hole = depth % 2 == 1;
+    }
+
+    tree
+}
+
+/// Order of the contours when every parent is followed by its children, depth first.
+pub fn depth_first_order(tree: &[Contour]) -> Vec<usize> {
+    fn visit(tree: &[Contour], index: usize, order: &mut Vec<usize>) {
+        order.push(index);
+        for &child in &tree[index].children {
+            visit(tree, child, order);
+        }
+    }
+
+    let mut order = Vec::with_capacity(tree.len());
+    for (i, contour) in tree.iter().enumerate() {
+        if contour.parent.is_none() {
+            visit(tree, i, &mut order);
+        }
+    }
+    order
+}
+
+impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
+    // Applies the hierarchy settings to a set of traced contours: holes can be dropped or drawn
+    // in the opposite direction of the outer contours, and the drawing order can follow the tree.
+    pub fn arrange_contours(&self, contours: Vec<Vec<(u32, u32)>>) -> Vec<Vec<(u32, u32)>> {
+        if !self.outer_only && !self.reverse_holes && !self.depth_first {
+            return contours;
+        }
+
+        let mut tree = build_hierarchy(contours);
+
+        if self.reverse_holes {
+            for contour in tree.iter_mut() {
+                if contour.clockwise == contour.hole {
+                    contour.reverse();
+                }
+            }
+        }
+
+        let order = match self.depth_first {
+            true => depth_first_order(&tree),
+            false => (0..tree.len()).collect(),
+        };
+
+        let order: Vec<usize> = order
+            .into_iter()
+            .filter(|&i| !(self.outer_only && tree[i].hole))
+            .collect();
+        order
+            .into_iter()
+            .map(|i| std::mem::take(&mut tree[i].points))
+            .collect()
+    }
+}
@@ -1,9 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
 /// Method enum
 /// Capture the different methods that can be used
-#[derive(Default, Clone, Copy, Debug, Serialize)]
+#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum Method {
     Outline,
     #[default]
@@ -13,6 +13,9 @@ pub enum Method {
     Black,
     Dynamic,
     Zigzag,
+    Stipple,
+    Tsp,
+    Spiral,
 }
 
 /// Implement Display for Method
@@ -33,6 +36,9 @@ impl From<Method> for &str {
             Method::Black => "black",
             Method::Dynamic => "dynamic",
             Method::Zigzag => "zigzag",
+            Method::Stipple => "stipple",
+            Method::Tsp => "tsp",
+            Method::Spiral => "spiral",
         }
     }
 }
@@ -50,6 +56,9 @@ impl TryFrom<&str> for Method {
             _ if value.contains("black") => Self::Black,
             _ if value.contains("dynamic") => Self::Dynamic,
             _ if value.contains("zigzag") => Self::Zigzag,
+            _ if value.contains("stipple") => Self::Stipple,
+            _ if value.contains("tsp") => Self::Tsp,
+            _ if value.contains("spiral") => Self::Spiral,
             _ => return Err(()),
         })
     }
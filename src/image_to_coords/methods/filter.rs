@@ -0,0 +1,92 @@
+use crate::image_to_coords::hierarchy::signed_area;
+use crate::image_to_coords::methods::utils::MOORE_DIRS;
+use crate::image_to_coords::request::Request;
+use image::GrayImage;
+
+impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
+    // Finds every group of connected black pixels that is smaller than the speckle size, these
+    // pixels are treated as white while tracing. The map covers the image that is traced, which
+    // does not have to be `size` by `size`.
+    pub fn find_speckles(&mut self) {
+        self.speckles = vec![];
+        let (width, height) = self.image.dimensions();
+        let stride = width as usize;
+        let mut speckles = vec![false; stride * height as usize];
+        let mut seen = vec![false; stride * height as usize];
+        let mut stack = vec![];
+        let mut component = vec![];
+
+        for y in 0..height {
+            for x in 0..width {
+                let index = y as usize * stride + x as usize;
+                if seen[index] || !self.is_black(x, y) {
+                    continue;
+                }
+
+                seen[index] = true;
+                stack.push((x, y));
+                component.clear();
+
+                while let Some((cx, cy)) = stack.pop() {
+                    component.push(cy as usize * stride + cx as usize);
+                    for (dx, dy) in &MOORE_DIRS {
+                        let nx = cx as i32 + dx;
+                        let ny = cy as i32 + dy;
+                        if nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32 {
+                            continue;
+                        }
+                        let neighbour = ny as usize * stride + nx as usize;
+                        if !seen[neighbour] && self.is_black(nx as u32, ny as u32) {
+                            seen[neighbour] = true;
+                            stack.push((nx as u32, ny as u32));
+                        }
+                    }
+                }
+
+                if component.len() < self.speckle_size as usize {
+                    for &pixel in &component {
+                        speckles[pixel] = true;
+                    }
+                }
+            }
+        }
+
+        self.speckles = speckles;
+    }
+
+    #[inline(always)]
+    pub fn is_speckle(&self, x: u32, y: u32) -> bool {
+        let index = y as usize * self.image.width() as usize + x as usize;
+        self.speckles.get(index).copied().unwrap_or(false)
+    }
+
+    // Drops contours that are too short or enclose too little area, and keeps only the contours
+    // that enclose the most area when there is a maximum amount of contours.
+    pub fn filter_contours(&self, contours: Vec<Vec<(u32, u32)>>) -> Vec<Vec<(u32, u32)>> {
+        let mut contours: Vec<(Vec<(u32, u32)>, f64)> = contours
+            .into_iter()
+            .map(|contour| {
+                let area = signed_area(&contour).abs();
+                (contour, area)
+            })
+            .filter(|(contour, area)| {
+                contour.len() >= self.min_contour_length as usize
+                    && *area >= self.min_contour_area as f64
+            })
+            .collect();
+
+        let max = self.max_contours as usize;
+        if max > 0 && contours.len() > max {
+            let mut by_area: Vec<usize> = (0..contours.len()).collect();
+            by_area.sort_by(|&a, &b| contours[b].1.total_cmp(&contours[a].1));
+            let mut keep = vec![false; contours.len()];
+            for &i in &by_area[..max] {
+                keep[i] = true;
+            }
+            let mut keep = keep.into_iter();
+            contours.retain(|_| keep.next().unwrap_or(false));
+        }
+
+        contours.into_iter().map(|(contour, _)| contour).collect()
+    }
+}
@@ -1,9 +1,14 @@
 mod black;
 mod dynamic;
+mod filter;
 mod full;
 mod outline;
 mod scanline;
 mod snake;
+mod spiral;
 mod spread;
+mod stipple;
+mod tour;
+mod tsp;
 mod utils;
 mod zigzag;
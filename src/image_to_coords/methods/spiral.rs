@@ -0,0 +1,54 @@
+use crate::image_to_coords::request::Request;
+use image::GrayImage;
+use std::f32::consts::TAU;
+
+// Distance in pixels the pen travels along the spiral between two evaluations.
+const STEP: f32 = 1.0;
+
+impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
+    // Walks an Archimedean spiral from the center of the image outwards. Depending on the spiral
+    // type the darkness under the pen either pushes the line outwards in a wobble (1), or decides
+    // how many samples are spent on that part of the spiral (2).
+    pub fn trace_spiral(&mut self) -> Vec<(f32, f32)> {
+        let spacing = self.spiral_spacing.max(1.0);
+        let center = self.size as f32 / 2.0;
+        let max_radius = center - spacing / 2.0;
+
+        let mut result = vec![];
+        let mut theta: f32 = 0.0;
+        let mut phase: f32 = 0.0;
+        let mut ink: f32 = 0.0;
+
+        loop {
+            let radius = spacing * theta / TAU;
+            if radius > max_radius {
+                break;
+            }
+            let angle = theta + self.spiral_start_angle.to_radians();
+            let (sin, cos) = angle.sin_cos();
+            let (x, y) = (center + radius * cos, center + radius * sin);
+            let darkness = self.darkness(x as u32, y as u32);
+
+            match self.spiral_type {
+                2 => {
+                    // Always keep a few samples on white areas, so the line stays unbroken.
+                    ink += darkness.max(0.05);
+                    if ink >= 1.0 {
+                        ink -= 1.0;
+                        result.push((x, y));
+                    }
+                }
+                _ => {
+                    let offset = 0.5 * spacing * darkness * phase.sin();
+                    result.push((x + offset * cos, y + offset * sin));
+                    phase += STEP * TAU / spacing;
+                }
+            }
+
+            // Keep the arc length between steps constant, the center is walked at a fixed rate.
+            theta += STEP / radius.max(spacing);
+        }
+
+        result
+    }
+}
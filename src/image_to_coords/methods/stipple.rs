@@ -0,0 +1,168 @@
+use crate::image_to_coords::request::Request;
+use image::GrayImage;
+use rand::Rng;
+
+// Largest amount of pixels sampled per axis when weighing the image, bigger images are sampled
+// with a stride to keep the relaxation fast.
+const MAX_SAMPLES_PER_AXIS: u32 = 512;
+
+// Improvement passes of the stipple tour. The passes stop as soon as one finds nothing to
+// shorten, so this is only an upper bound; the tsp iteration setting belongs to the tsp method.
+const TOUR_PASSES: u32 = 32;
+
+// Bucket grid used to find the closest stipple point for a pixel without checking every point.
+struct PointGrid {
+    cell: f32,
+    cols: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl PointGrid {
+    fn new(points: &[(f32, f32)], size: u32) -> PointGrid {
+        let area = size as f32 * size as f32;
+        let cell = (area / points.len().max(1) as f32).sqrt().max(1.0);
+        let cols = (size as f32 / cell).ceil().max(1.0) as usize;
+        let mut cells = vec![vec![]; cols * cols];
+        for (i, (x, y)) in points.iter().enumerate() {
+            let (cx, cy) = Self::cell_of(cell, cols, *x, *y);
+            cells[cy * cols + cx].push(i);
+        }
+        PointGrid { cell, cols, cells }
+    }
+
+    fn cell_of(cell: f32, cols: usize, x: f32, y: f32) -> (usize, usize) {
+        let cx = ((x / cell) as usize).min(cols - 1);
+        let cy = ((y / cell) as usize).min(cols - 1);
+        (cx, cy)
+    }
+
+    // Search rings of cells around the pixel until no closer point can exist.
+    fn nearest(&self, points: &[(f32, f32)], x: f32, y: f32) -> Option<usize> {
+        let (cx, cy) = Self::cell_of(self.cell, self.cols, x, y);
+        let mut best: Option<(usize, f32)> = None;
+
+        for ring in 0..self.cols as i32 {
+            for dy in -ring..=ring {
+                for dx in -ring..=ring {
+                    if dx.abs() != ring && dy.abs() != ring {
+                        continue;
+                    }
+                    let nx = cx as i32 + dx;
+                    let ny = cy as i32 + dy;
+                    if nx < 0 || ny < 0 || nx >= self.cols as i32 || ny >= self.cols as i32 {
+                        continue;
+                    }
+                    for &i in &self.cells[ny as usize * self.cols + nx as usize] {
+                        let (px, py) = points[i];
+                        let d = (px - x).powi(2) + (py - y).powi(2);
+                        if best.is_none_or(|(_, best_d)| d < best_d) {
+                            best = Some((i, d));
+                        }
+                    }
+                }
+            }
+
+            if let Some((_, best_d)) = best {
+                let reach = ring as f32 * self.cell;
+                if best_d <= reach * reach {
+                    break;
+                }
+            }
+        }
+
+        best.map(|(i, _)| i)
+    }
+}
+
+impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
+    // Darkness of a pixel between 0.0 (white) and 1.0 (black).
+    pub fn darkness(&self, x: u32, y: u32) -> f32 {
+        match self.image.get_pixel_checked(x, y) {
+            Some(pixel) => 1.0 - pixel[0] as f32 / 255.0,
+            None => 0.0,
+        }
+    }
+
+    fn sample_stride(&self) -> u32 {
+        self.size.div_ceil(MAX_SAMPLES_PER_AXIS).max(1)
+    }
+
+    // Randomly places points with a density proportional to the darkness of the image.
+    pub fn sample_by_darkness(&self, amount: usize) -> Vec<(f32, f32)> {
+        let stride = self.sample_stride();
+
+        let mut cells = vec![];
+        let mut cumulative = vec![];
+        let mut total = 0.0;
+        for y in (0..self.size).step_by(stride as usize) {
+            for x in (0..self.size).step_by(stride as usize) {
+                let weight = self.darkness(x, y);
+                if weight > 0.0 {
+                    total += weight;
+                    cells.push((x, y));
+                    cumulative.push(total);
+                }
+            }
+        }
+
+        if cells.is_empty() {
+            return vec![];
+        }
+
+        let mut rng = rand::rng();
+        (0..amount)
+            .map(|_| {
+                let target = rng.random::<f32>() * total;
+                let index = cumulative
+                    .partition_point(|&c| c < target)
+                    .min(cells.len() - 1);
+                let (x, y) = cells[index];
+                (
+                    x as f32 + rng.random::<f32>() * stride as f32,
+                    y as f32 + rng.random::<f32>() * stride as f32,
+                )
+            })
+            .collect()
+    }
+
+    // Weighted Voronoi stippling: every iteration moves each point to the darkness weighted
+    // centroid of the pixels closest to it, which spreads the points out like blue noise while
+    // keeping their density proportional to the darkness.
+    pub fn relax_stipples(&self, points: &mut [(f32, f32)]) {
+        let stride = self.sample_stride();
+
+        for _ in 0..self.stipple_iterations {
+            let grid = PointGrid::new(points, self.size);
+            let mut sums = vec![(0.0f32, 0.0f32, 0.0f32); points.len()];
+
+            for y in (0..self.size).step_by(stride as usize) {
+                for x in (0..self.size).step_by(stride as usize) {
+                    let weight = self.darkness(x, y);
+                    if weight <= 0.0 {
+                        continue;
+                    }
+                    if let Some(i) = grid.nearest(points, x as f32, y as f32) {
+                        sums[i].0 += weight;
+                        sums[i].1 += weight * x as f32;
+                        sums[i].2 += weight * y as f32;
+                    }
+                }
+            }
+
+            for (point, (w, wx, wy)) in points.iter_mut().zip(sums) {
+                if w > 0.0 {
+                    *point = (wx / w, wy / w);
+                }
+            }
+        }
+    }
+
+    // Places stipple points, relaxes them and orders them into a single tour. The points keep
+    // their sub pixel positions, rounding them to the pixel grid would bring back the lattice
+    // the relaxation removes.
+    pub fn trace_stipple(&mut self) -> Vec<(f32, f32)> {
+        let mut points = self.sample_by_darkness(self.stipple_points as usize);
+        self.relax_stipples(&mut points);
+        self.order_points(&points, TOUR_PASSES)
+    }
+}
@@ -0,0 +1,237 @@
+// Helpers for ordering loose points into a single path, so the beam travels as little as possible
+// between consecutive samples.
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+/// Greedy tour: start at the point closest to `start` and keep walking to the closest unvisited
+/// point. Returns the visiting order as indices into `points`.
+pub fn nearest_neighbour_tour(points: &[(f32, f32)], start: (f32, f32)) -> Vec<usize> {
+    if points.is_empty() {
+        return vec![];
+    }
+
+    let mut visited = vec![false; points.len()];
+    let mut order = Vec::with_capacity(points.len());
+
+    let mut current = (0..points.len())
+        .min_by(|&a, &b| distance(points[a], start).total_cmp(&distance(points[b], start)))
+        .unwrap_or(0);
+
+    loop {
+        visited[current] = true;
+        order.push(current);
+
+        let next = (0..points.len()).filter(|&i| !visited[i]).min_by(|&a, &b| {
+            distance(points[a], points[current]).total_cmp(&distance(points[b], points[current]))
+        });
+
+        match next {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+
+    order
+}
+
+// Candidate partners per point. A short tour only connects points that are close together, so
+// the passes below only try the closest points, which keeps every pass close to linear.
+const NEIGHBOURS: usize = 8;
+
+// The `k` closest points of every point, closest first, found through a grid of buckets with
+// about two points per bucket.
+fn neighbour_lists(points: &[(f32, f32)], k: usize) -> Vec<Vec<usize>> {
+    let (min_x, min_y, max_x, max_y) = points.iter().fold(
+        (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+        |(min_x, min_y, max_x, max_y), &(x, y)| {
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+        },
+    );
+    let cols = ((points.len() as f32 / 2.0).sqrt().ceil() as usize).max(1);
+    let cell = ((max_x - min_x).max(max_y - min_y) / cols as f32).max(f32::EPSILON);
+    let cell_of = |(x, y): (f32, f32)| {
+        (
+            (((x - min_x) / cell) as usize).min(cols - 1),
+            (((y - min_y) / cell) as usize).min(cols - 1),
+        )
+    };
+    let mut cells = vec![vec![]; cols * cols];
+    for (i, &point) in points.iter().enumerate() {
+        let (cx, cy) = cell_of(point);
+        cells[cy * cols + cx].push(i);
+    }
+
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &point)| {
+            let (cx, cy) = cell_of(point);
+            let mut found: Vec<(f32, usize)> = vec![];
+            for ring in 0..cols as i32 {
+                for dy in -ring..=ring {
+                    for dx in -ring..=ring {
+                        if dx.abs() != ring && dy.abs() != ring {
+                            continue;
+                        }
+                        let (nx, ny) = (cx as i32 + dx, cy as i32 + dy);
+                        if nx < 0 || ny < 0 || nx >= cols as i32 || ny >= cols as i32 {
+                            continue;
+                        }
+                        for &j in &cells[ny as usize * cols + nx as usize] {
+                            if j != i {
+                                found.push((distance(point, points[j]), j));
+                            }
+                        }
+                    }
+                }
+                // Points outside the searched rings are at least `ring` cells away.
+                if found.len() >= k {
+                    found.sort_by(|a, b| a.0.total_cmp(&b.0));
+                    if found[k - 1].0 <= ring as f32 * cell {
+                        break;
+                    }
+                }
+            }
+            found.sort_by(|a, b| a.0.total_cmp(&b.0));
+            found.into_iter().take(k).map(|(_, j)| j).collect()
+        })
+        .collect()
+}
+
+// Where every point is in the tour.
+fn positions(tour: &[usize]) -> Vec<usize> {
+    let mut position = vec![0; tour.len()];
+    for (p, &point) in tour.iter().enumerate() {
+        position[point] = p;
+    }
+    position
+}
+
+// One pass of 2-opt: whenever two edges of the tour cross (or could be shortened by swapping
+// their endpoints), the part of the tour between them is reversed. Every edge is only tried
+// against the edges that start at the neighbours of its first point.
+fn two_opt_pass(points: &[(f32, f32)], tour: &mut [usize], neighbours: &[Vec<usize>]) -> bool {
+    let n = tour.len();
+    if n < 4 {
+        return false;
+    }
+    let mut position = positions(tour);
+    let mut improved = false;
+
+    for i in 0..n {
+        let a = tour[i];
+        let b = tour[(i + 1) % n];
+        let ab = distance(points[a], points[b]);
+        for &c in &neighbours[a] {
+            let ac = distance(points[a], points[c]);
+            // Neighbours are sorted, no further one can shorten the edge.
+            if ac >= ab {
+                break;
+            }
+            let j = position[c];
+            let d = tour[(j + 1) % n];
+            if c == b || d == a {
+                continue;
+            }
+
+            let delta = ac + distance(points[b], points[d]) - ab - distance(points[c], points[d]);
+            if delta < -1e-3 {
+                let (from, to) = if i < j { (i + 1, j) } else { (j + 1, i) };
+                tour[from..=to].reverse();
+                for (p, &point) in tour.iter().enumerate().take(to + 1).skip(from) {
+                    position[point] = p;
+                }
+                improved = true;
+                break;
+            }
+        }
+    }
+
+    improved
+}
+
+// One pass of Or-opt: short segments of one to three points are cut out and reinserted (possibly
+// reversed) between two other points when that shortens the tour. A segment is only tried next
+// to the neighbours of its ends, on the edge before and the edge after every neighbour.
+fn or_opt_pass(points: &[(f32, f32)], tour: &mut Vec<usize>, neighbours: &[Vec<usize>]) -> bool {
+    let n = tour.len();
+    if n < 5 {
+        return false;
+    }
+    let mut position = positions(tour);
+    let mut improved = false;
+
+    for len in 1..=3 {
+        let mut i = 0;
+        while i + len <= n {
+            let prev = points[tour[(i + n - 1) % n]];
+            let first = points[tour[i]];
+            let last = points[tour[i + len - 1]];
+            let next = points[tour[(i + len) % n]];
+            let gain = distance(prev, first) + distance(last, next) - distance(prev, next);
+
+            // (edge start, reversed, cost)
+            let mut best: Option<(usize, bool, f32)> = None;
+            let candidates = neighbours[tour[i]]
+                .iter()
+                .chain(&neighbours[tour[i + len - 1]]);
+            for &m in candidates {
+                for j in [position[m], (position[m] + n - 1) % n] {
+                    if (j >= i && j < i + len) || j == (i + n - 1) % n {
+                        continue;
+                    }
+                    let p = points[tour[j]];
+                    let q = points[tour[(j + 1) % n]];
+                    let forward = distance(p, first) + distance(last, q) - distance(p, q);
+                    let reversed = distance(p, last) + distance(first, q) - distance(p, q);
+                    let (reverse, cost) = if reversed < forward {
+                        (true, reversed)
+                    } else {
+                        (false, forward)
+                    };
+                    if best.is_none_or(|(_, _, best_cost)| cost < best_cost) {
+                        best = Some((j, reverse, cost));
+                    }
+                }
+            }
+
+            if let Some((j, reverse, cost)) = best
+                && cost < gain - 1e-3
+            {
+                let mut segment: Vec<usize> = tour.drain(i..i + len).collect();
+                if reverse {
+                    segment.reverse();
+                }
+                let at = if j < i { j + 1 } else { j + 1 - len };
+                tour.splice(at..at, segment);
+                let (from, to) = (at.min(i), (at.max(i) + len).min(n));
+                for (p, &point) in tour.iter().enumerate().take(to).skip(from) {
+                    position[point] = p;
+                }
+                improved = true;
+            }
+
+            i += 1;
+        }
+    }
+
+    improved
+}
+
+/// Shortens a closed tour with alternating 2-opt and Or-opt passes. Stops early when a pass
+/// finds nothing to improve, otherwise after `iterations` passes.
+pub fn improve_tour(points: &[(f32, f32)], tour: &mut Vec<usize>, iterations: u32) {
+    if iterations == 0 || points.len() < 4 {
+        return;
+    }
+    let neighbours = neighbour_lists(points, NEIGHBOURS);
+    for _ in 0..iterations {
+        let two_opt = two_opt_pass(points, tour, &neighbours);
+        let or_opt = or_opt_pass(points, tour, &neighbours);
+        if !two_opt && !or_opt {
+            break;
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::image_to_coords::methods::tour::{improve_tour, nearest_neighbour_tour};
+use crate::image_to_coords::request::Request;
+use image::GrayImage;
+
+impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
+    // Orders points into a closed tour, first greedily and then shortened by at most
+    // `iterations` improvement passes. The points keep their sub pixel positions.
+    pub fn order_points(&self, points: &[(f32, f32)], iterations: u32) -> Vec<(f32, f32)> {
+        let (sx, sy) = self.get_starting_point();
+        let mut tour = nearest_neighbour_tour(points, (sx as f32, sy as f32));
+        improve_tour(points, &mut tour, iterations);
+
+        let max = self.size as f32;
+        tour.into_iter()
+            .map(|i| {
+                let (x, y) = points[i];
+                (x.clamp(0.0, max), y.clamp(0.0, max))
+            })
+            .collect()
+    }
+
+    // Samples points by darkness and connects them with a single approximate travelling salesman
+    // tour.
+    pub fn trace_tsp(&mut self) -> Vec<(f32, f32)> {
+        let points = self.sample_by_darkness(self.tsp_points as usize);
+        self.order_points(&points, self.tsp_iterations)
+    }
+}
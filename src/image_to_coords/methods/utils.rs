@@ -39,7 +39,7 @@ impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
     #[inline(always)]
     pub fn is_black(&self, x: u32, y: u32) -> bool {
         if let Some(pixel) = self.image.get_pixel_checked(x, y) {
-            pixel[0] < self.threshold
+            pixel[0] < self.threshold && !self.is_speckle(x, y)
         } else {
             false
         }
@@ -58,7 +58,7 @@ impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
                 && ny >= 0
                 && (nx as u32) < self.size
                 && (ny as u32) < self.size
-                && self.image.get_pixel(nx as u32, ny as u32)[0] >= self.threshold
+                && !self.is_black(nx as u32, ny as u32)
             {
                 edge_count += 1;
                 if edge_count == self.edge_threshold {
@@ -1,4 +1,5 @@
+pub mod hierarchy;
 pub mod method;
 mod methods;
-mod processing_utils;
+pub mod processing_utils;
 pub mod request;
@@ -3,6 +3,45 @@ use image::GrayImage;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 
+// Simple interpolation for coords, shared by every source that produces coords.
+pub fn interpolate_coords(coords: &[(f32, f32)], int_amount: usize) -> Vec<(f32, f32)> {
+    let mut result = Vec::new();
+    for i in 0..coords.len() {
+        let (x0, y0) = coords[i];
+        let (x1, y1) = coords[(i + 1) % coords.len()];
+        for j in 0..int_amount {
+            let t = j as f32 / int_amount as f32;
+            result.push((x0 + t * (x1 - x0), y0 + t * (y1 - y0)));
+        }
+    }
+    result
+}
+
+// Resamples coords to an exact amount by walking the original order with linear steps, jumps
+// between contours stay jumps.
+pub fn resample_coords(coords: &[(f32, f32)], amount: usize) -> Vec<(f32, f32)> {
+    if coords.len() < 2 {
+        return vec![coords.first().copied().unwrap_or((0.0, 0.0)); amount];
+    }
+    let step = coords.len() as f32 / amount.max(1) as f32;
+    (0..amount)
+        .map(|i| {
+            let position = i as f32 * step;
+            let index = (position as usize).min(coords.len() - 1);
+            let t = position - index as f32;
+            let (x0, y0) = coords[index];
+            let (x1, y1) = coords[(index + 1) % coords.len()];
+            (x0 + t * (x1 - x0), y0 + t * (y1 - y0))
+        })
+        .collect()
+}
+
+// Scramble the coords
+pub fn scramble_coords(mut coords: Vec<(f32, f32)>) -> Vec<(f32, f32)> {
+    coords.shuffle(&mut thread_rng());
+    coords
+}
+
 impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
     // Divide point map by height & width to create floats, we need these for generating audio.
     pub fn normalize(&self, outline: &[(u32, u32)]) -> Vec<(f32, f32)> {
@@ -17,28 +56,29 @@ impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
             .collect()
     }
 
+    // Same as normalize, for points that were traced with sub pixel precision.
+    pub fn normalize_precise(&self, points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+        points
+            .iter()
+            .map(|(x, y)| {
+                (
+                    (x / self.size as f32) * 2.0 - 1.0, // X in [-1.0, 1.0]
+                    (y / self.size as f32) * 2.0 - 1.0, // Y in [-1.0, 1.0]
+                )
+            })
+            .collect()
+    }
+
     // Simple interpolation for coords
     pub fn interpolate(&self, coords: &[(f32, f32)]) -> Vec<(f32, f32)> {
-        let mut result = Vec::new();
         match self.interpolate {
-            true => {
-                for i in 0..coords.len() {
-                    let (x0, y0) = coords[i];
-                    let (x1, y1) = coords[(i + 1) % coords.len()];
-                    for j in 0..self.int_amount {
-                        let t = j as f32 / self.int_amount as f32;
-                        result.push((x0 + t * (x1 - x0), y0 + t * (y1 - y0)));
-                    }
-                }
-                result
-            }
+            true => interpolate_coords(coords, self.int_amount),
             false => coords.to_vec(),
         }
     }
     // Scramble the results
-    pub fn scrambler(&self, mut coords: Vec<(f32, f32)>) -> Vec<(f32, f32)> {
-        coords.shuffle(&mut thread_rng());
-        coords
+    pub fn scrambler(&self, coords: Vec<(f32, f32)>) -> Vec<(f32, f32)> {
+        scramble_coords(coords)
     }
 
     pub fn to_slice_refs<'a>(&self, vecs: &'a Vec<Vec<(u32, u32)>>) -> Vec<&'a [(u32, u32)]> {
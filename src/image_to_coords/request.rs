@@ -9,6 +9,7 @@ pub struct Request<Im: std::ops::Deref<Target = GrayImage>> {
     pub pix_threshold: u32,
     pub spread_type: u32,
     pub result: Vec<(f32, f32)>,
+    pub contour_starts: Vec<usize>,
     pub interpolate: bool,
     pub int_amount: usize,
     pub size: u32,
@@ -24,6 +25,21 @@ pub struct Request<Im: std::ops::Deref<Target = GrayImage>> {
     pub double_trace: bool,
     pub edge_threshold: u8,
     pub flatten: bool,
+    pub stipple_points: u32,
+    pub stipple_iterations: u32,
+    pub tsp_points: u32,
+    pub tsp_iterations: u32,
+    pub spiral_spacing: f32,
+    pub spiral_start_angle: f32,
+    pub spiral_type: u32,
+    pub outer_only: bool,
+    pub reverse_holes: bool,
+    pub depth_first: bool,
+    pub min_contour_length: u32,
+    pub min_contour_area: f32,
+    pub max_contours: u32,
+    pub speckle_size: u32,
+    pub speckles: Vec<bool>,
 }
 
 /// Every request gets handeled in the Request struct for easy accessibility to variables and to
@@ -31,6 +47,9 @@ pub struct Request<Im: std::ops::Deref<Target = GrayImage>> {
 impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
     // Method dispatcher
     pub fn process(&mut self) {
+        if self.speckle_size > 1 {
+            self.find_speckles();
+        }
         match self.method {
             Method::Outline => Self::outline(self),
             Method::Full => Self::full_contour(self),
@@ -39,6 +58,9 @@ impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
             Method::Black => Self::black(self),
             Method::Dynamic => Self::dynamic(self),
             Method::Zigzag => Self::zigzag(self),
+            Method::Stipple => Self::stipple(self),
+            Method::Tsp => Self::tsp(self),
+            Method::Spiral => Self::spiral(self),
         }
     }
     // Processing helpers.
@@ -48,19 +70,31 @@ impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
         let normalized = self.normalize(outline);
         let scrambled = self.scrambler(normalized);
         self.result = self.interpolate(&scrambled);
+        self.contour_starts = vec![0];
+    }
+
+    // Process results that were traced with sub pixel precision. These are tours and spirals
+    // whose order is the drawing, so they are not scrambled.
+    pub fn process_result_precise(&mut self, points: &[(f32, f32)]) {
+        let normalized = self.normalize_precise(points);
+        self.result = self.interpolate(&normalized);
+        self.contour_starts = vec![0];
     }
 
     // Process results that were collected into nested arrays where each array has to be processed
-    // individually.
+    // individually. The start of every array is kept, so contours can be told apart later.
     pub fn process_result_vec(&mut self, outlines: &[&[(u32, u32)]]) {
-        self.result = outlines
-            .iter()
-            .flat_map(|outline| {
-                let normalized = self.normalize(outline);
-                let scrambled = self.scrambler(normalized);
-                self.interpolate(&scrambled)
-            })
-            .collect();
+        self.result = Vec::new();
+        self.contour_starts = Vec::new();
+        for outline in outlines {
+            let normalized = self.normalize(outline);
+            let scrambled = self.scrambler(normalized);
+            let interpolated = self.interpolate(&scrambled);
+            if !interpolated.is_empty() {
+                self.contour_starts.push(self.result.len());
+                self.result.extend(interpolated);
+            }
+        }
     }
 
     // Checks if nested arrays need to be flattened before or after processing.
@@ -94,6 +128,8 @@ impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
     // Full contour finds every outline.
     pub fn full_contour(&mut self) {
         let result = self.trace_all_outlines();
+        let result = self.filter_contours(result);
+        let result = self.arrange_contours(result);
         self.check_flatten(&self.to_slice_refs(&result));
     }
 
@@ -121,6 +157,29 @@ impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
 
     pub fn dynamic(&mut self) {
         let result = self.dynamic_contour_parser();
+        let result = self.filter_contours(result);
+        let result = self.arrange_contours(result);
         self.check_flatten(&self.to_slice_refs(&result));
     }
+
+    // Stipple method places dots with a density that follows the darkness of the image and
+    // visits them in a single tour, so photographs come out as a continuous dot drawing.
+    pub fn stipple(&mut self) {
+        let result = self.trace_stipple();
+        self.process_result_precise(&result);
+    }
+
+    // Tsp method draws the image as one continuous closed line through points sampled by
+    // darkness.
+    pub fn tsp(&mut self) {
+        let result = self.trace_tsp();
+        self.process_result_precise(&result);
+    }
+
+    // Spiral method draws the image with a single Archimedean spiral from the center, the
+    // darkness of the image modulates the wobble or the sample density along the spiral.
+    pub fn spiral(&mut self) {
+        let result = self.trace_spiral();
+        self.process_result_precise(&result);
+    }
 }
@@ -0,0 +1,119 @@
+use crate::audio_to_spectrum::request::hann;
+use image::GrayImage;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use realfft::RealFftPlanner;
+use realfft::num_complex::Complex;
+use std::f32::consts::TAU;
+
+// Frames overlap by three quarters, which keeps the summed Hann windows flat.
+const OVERLAP: usize = 4;
+
+pub struct Request<Im: std::ops::Deref<Target = GrayImage>> {
+    pub image: Im,
+    pub sample_rate: u32,
+    pub fft_size: usize,
+    pub duration: f32,
+    pub min_frequency: f32,
+    pub max_frequency: f32,
+    pub result: Vec<(f32, f32)>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<Im: std::ops::Deref<Target = GrayImage>> Request<Im> {
+    // The columns of the image are spread over the duration and the rows over the frequency
+    // range, the top row is the highest frequency. Dark pixels are loud, like the ink the
+    // tracing methods follow. Every bin keeps its phase running between frames, the right
+    // channel is a quarter cycle behind the left one, so the spectrogram is the same on both
+    // channels while the scope draws a cloud instead of a line. The result peaks at 1.0.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let size = self.fft_size.max(OVERLAP * 2) & !1;
+        let hop = size / OVERLAP;
+        let length = (self.duration.max(0.0) * self.sample_rate as f32).round() as usize;
+        let (width, height) = self.image.dimensions();
+        if width == 0 || height == 0 {
+            return Err(anyhow::anyhow!("The image is empty."));
+        }
+
+        let fft = RealFftPlanner::<f32>::new().plan_fft_inverse(size);
+        let window = hann(size);
+        let bins = size / 2 + 1;
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut phases: Vec<f32> = (0..bins).map(|_| rng.random_range(0.0..TAU)).collect();
+
+        let mut left = vec![0.0; length + size];
+        let mut right = vec![0.0; length + size];
+        let mut spectrum = fft.make_input_vec();
+        let mut output = fft.make_output_vec();
+
+        for frame in 0..=length / hop + 1 {
+            let column = (frame * hop) as f32 / length.max(1) as f32 * (width - 1) as f32;
+            let magnitudes = self.magnitudes(column, bins, size);
+
+            for (quadrature, channel) in [(false, &mut left), (true, &mut right)] {
+                for (bin, value) in spectrum.iter_mut().enumerate() {
+                    let phase = phases[bin] - if quadrature { TAU / 4.0 } else { 0.0 };
+                    *value = Complex::from_polar(magnitudes[bin], phase);
+                }
+                // The first and last bin of a real signal have no imaginary part.
+                spectrum[0] = Complex::new(0.0, 0.0);
+                spectrum[bins - 1] = Complex::new(0.0, 0.0);
+                fft.process(&mut spectrum, &mut output)?;
+
+                let start = frame * hop;
+                for (i, sample) in output.iter().enumerate() {
+                    if let Some(target) = channel.get_mut(start + i) {
+                        *target += sample * window[i];
+                    }
+                }
+            }
+
+            for (bin, phase) in phases.iter_mut().enumerate() {
+                *phase = (*phase + TAU * bin as f32 * hop as f32 / size as f32) % TAU;
+            }
+        }
+
+        // Half a frame is skipped, so the first column starts at the start of the clip.
+        let offset = size / 2;
+        let peak = left[offset..offset + length]
+            .iter()
+            .chain(&right[offset..offset + length])
+            .fold(0.0f32, |peak, s| peak.max(s.abs()))
+            .max(f32::EPSILON);
+        self.result = (offset..offset + length)
+            .map(|i| (left[i] / peak, right[i] / peak))
+            .collect();
+        Ok(())
+    }
+
+    // Magnitude of every bin at a column of the image, the pixels are interpolated in between
+    // rows and columns.
+    fn magnitudes(&self, column: f32, bins: usize, size: usize) -> Vec<f32> {
+        let (width, height) = self.image.dimensions();
+        let bin_width = self.sample_rate as f32 / size as f32;
+        let max_frequency = self.max_frequency.min(self.sample_rate as f32 / 2.0);
+        let range = (max_frequency - self.min_frequency).max(f32::EPSILON);
+
+        let darkness = |x: u32, y: u32| 1.0 - self.image.get_pixel(x, y)[0] as f32 / 255.0;
+        let x0 = (column as u32).min(width - 1);
+        let x1 = (x0 + 1).min(width - 1);
+        let tx = column - x0 as f32;
+
+        (0..bins)
+            .map(|bin| {
+                let frequency = bin as f32 * bin_width;
+                if frequency < self.min_frequency || frequency > max_frequency {
+                    return 0.0;
+                }
+                let row = (max_frequency - frequency) / range * (height - 1) as f32;
+                let y0 = (row as u32).min(height - 1);
+                let y1 = (y0 + 1).min(height - 1);
+                let ty = row - y0 as f32;
+                let top = darkness(x0, y0) * (1.0 - tx) + darkness(x1, y0) * tx;
+                let bottom = darkness(x0, y1) * (1.0 - tx) + darkness(x1, y1) * tx;
+                top * (1.0 - ty) + bottom * ty
+            })
+            .collect()
+    }
+}
@@ -2,16 +2,33 @@ use crate::interface::Interface;
 use log::info;
 use wasm_bindgen::prelude::*;
 
+mod analysis;
 mod audio_to_coords;
+mod audio_to_spectrum;
+mod audio_to_window;
+mod automation;
 mod backend;
 mod coords_to_audio;
+mod coords_to_frames;
+mod coords_to_vector;
+mod effects;
+mod frames_to_coords;
 mod get_requests;
 mod image_to_coords;
+mod image_to_spectrogram;
 mod interface;
+mod mesh_to_coords;
+mod midi_to_coords;
 mod process_requests;
+mod scene_to_coords;
 mod set_requests;
+mod shape_to_coords;
+mod svg_to_coords;
+mod text_to_coords;
 mod traits;
+mod transform_coords;
 mod utils;
+mod visualizer;
 
 pub fn to_js<E: std::fmt::Display>(e: E) -> JsValue {
     JsValue::from_str(&e.to_string())
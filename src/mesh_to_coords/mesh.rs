@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+use std::f32::consts::TAU;
+
+// Rings and segments of the built-in torus.
+const TORUS_RINGS: usize = 16;
+const TORUS_SEGMENTS: usize = 8;
+const TORUS_THICKNESS: f32 = 0.4;
+
+pub type Vertex = [f32; 3];
+
+/// Vertices and the edges between them, every edge is stored once.
+#[derive(Debug, Clone, Default)]
+pub struct Mesh {
+    pub vertices: Vec<Vertex>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl Mesh {
+    fn new(vertices: Vec<Vertex>, edges: impl IntoIterator<Item = (usize, usize)>) -> Mesh {
+        let unique: BTreeSet<(usize, usize)> = edges
+            .into_iter()
+            .filter(|(a, b)| a != b && *a < vertices.len() && *b < vertices.len())
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        let mut mesh = Mesh {
+            vertices,
+            edges: unique.into_iter().collect(),
+        };
+        mesh.normalize();
+        mesh
+    }
+
+    pub fn cube() -> Mesh {
+        let vertices = (0..8)
+            .map(|i| {
+                let bit = |b: usize| if i >> b & 1 == 1 { 1.0 } else { -1.0 };
+                [bit(0), bit(1), bit(2)]
+            })
+            .collect();
+        // Corners are connected when they differ in exactly one axis.
+        let edges = (0..8usize).flat_map(|a| (0..3).map(move |b| (a, a ^ (1 << b))));
+        Mesh::new(vertices, edges)
+    }
+
+    pub fn icosahedron() -> Mesh {
+        let phi = (1.0 + 5.0f32.sqrt()) / 2.0;
+        let mut vertices = vec![];
+        for a in [-1.0, 1.0] {
+            for b in [-phi, phi] {
+                vertices.push([0.0, a, b]);
+                vertices.push([a, b, 0.0]);
+                vertices.push([b, 0.0, a]);
+            }
+        }
+        // Neighbours are exactly one edge length (2.0) apart.
+        let n = vertices.len();
+        let edges: Vec<(usize, usize)> = (0..n)
+            .flat_map(|a| (0..n).map(move |b| (a, b)))
+            .filter(|&(a, b)| {
+                let distance: f32 = (0..3)
+                    .map(|i| (vertices[a][i] - vertices[b][i]).powi(2))
+                    .sum::<f32>()
+                    .sqrt();
+                (distance - 2.0).abs() < 1e-3
+            })
+            .collect();
+        Mesh::new(vertices, edges)
+    }
+
+    pub fn torus() -> Mesh {
+        let mut vertices = vec![];
+        let mut edges = vec![];
+        let index = |ring: usize, segment: usize| {
+            (ring % TORUS_RINGS) * TORUS_SEGMENTS + segment % TORUS_SEGMENTS
+        };
+        for ring in 0..TORUS_RINGS {
+            let u = ring as f32 / TORUS_RINGS as f32 * TAU;
+            for segment in 0..TORUS_SEGMENTS {
+                let v = segment as f32 / TORUS_SEGMENTS as f32 * TAU;
+                let radius = 1.0 + TORUS_THICKNESS * v.cos();
+                vertices.push([
+                    radius * u.cos(),
+                    TORUS_THICKNESS * v.sin(),
+                    radius * u.sin(),
+                ]);
+                edges.push((index(ring, segment), index(ring + 1, segment)));
+                edges.push((index(ring, segment), index(ring, segment + 1)));
+            }
+        }
+        Mesh::new(vertices, edges)
+    }
+
+    /// Reads the vertices, faces and lines of a wavefront obj file. Faces become their outline
+    /// edges, everything else in the file is ignored.
+    pub fn from_obj(text: &str) -> Mesh {
+        let mut vertices: Vec<Vertex> = vec![];
+        let mut edges = vec![];
+
+        for line in text.lines() {
+            let mut parts = line.split_whitespace();
+            match parts.next() {
+                Some("v") => {
+                    let values: Vec<f32> = parts.take(3).filter_map(|p| p.parse().ok()).collect();
+                    if let [x, y, z] = values[..] {
+                        vertices.push([x, y, z]);
+                    }
+                }
+                Some(kind @ ("f" | "l")) => {
+                    // Indices start at 1, negative indices count back from the last vertex.
+                    let indices: Vec<usize> = parts
+                        .filter_map(|p| p.split('/').next()?.parse::<i64>().ok())
+                        .filter_map(|i| match i {
+                            1.. => Some(i as usize - 1),
+                            ..0 => vertices.len().checked_sub(i.unsigned_abs() as usize),
+                            0 => None,
+                        })
+                        .collect();
+                    edges.extend(indices.windows(2).map(|w| (w[0], w[1])));
+                    if kind == "f" && indices.len() > 2 {
+                        edges.push((indices[indices.len() - 1], indices[0]));
+                    }
+                }
+                _ => {}
+            }
+        }
+        Mesh::new(vertices, edges)
+    }
+
+    // Centers the mesh and scales it to fit in a unit sphere.
+    fn normalize(&mut self) {
+        if self.vertices.is_empty() {
+            return;
+        }
+        let mut center = [0.0; 3];
+        for axis in 0..3 {
+            let min = self
+                .vertices
+                .iter()
+                .map(|v| v[axis])
+                .fold(f32::MAX, f32::min);
+            let max = self
+                .vertices
+                .iter()
+                .map(|v| v[axis])
+                .fold(f32::MIN, f32::max);
+            center[axis] = (min + max) / 2.0;
+        }
+        let radius = self
+            .vertices
+            .iter()
+            .map(|v| {
+                (0..3)
+                    .map(|i| (v[i] - center[i]).powi(2))
+                    .sum::<f32>()
+                    .sqrt()
+            })
+            .fold(0.0, f32::max)
+            .max(f32::EPSILON);
+        for vertex in self.vertices.iter_mut() {
+            for axis in 0..3 {
+                vertex[axis] = (vertex[axis] - center[axis]) / radius;
+            }
+        }
+    }
+
+    /// Chains the edges into as few strokes as possible. Every stroke keeps walking over unused
+    /// edges, and when it gets stuck the next stroke starts at the closest vertex that still has
+    /// unused edges, preferring vertices with an odd amount of them. The order only depends on
+    /// the mesh, so it stays the same in every frame.
+    pub fn strokes(&self) -> Vec<Vec<usize>> {
+        let mut neighbours: Vec<Vec<(usize, usize)>> = vec![vec![]; self.vertices.len()];
+        for (edge, &(a, b)) in self.edges.iter().enumerate() {
+            neighbours[a].push((b, edge));
+            neighbours[b].push((a, edge));
+        }
+        let mut used = vec![false; self.edges.len()];
+        let mut unused_degree: Vec<usize> = neighbours.iter().map(|n| n.len()).collect();
+        let mut remaining = self.edges.len();
+
+        let mut strokes = vec![];
+        let mut position = [0.0; 3];
+        while remaining > 0 {
+            let distance = |v: &Vertex| (0..3).map(|i| (v[i] - position[i]).powi(2)).sum::<f32>();
+            let Some(start) = (0..self.vertices.len())
+                .filter(|&v| unused_degree[v] > 0)
+                .min_by(|&a, &b| {
+                    let even = |v: usize| unused_degree[v].is_multiple_of(2);
+                    even(a)
+                        .cmp(&even(b))
+                        .then(distance(&self.vertices[a]).total_cmp(&distance(&self.vertices[b])))
+                })
+            else {
+                break;
+            };
+
+            let mut stroke = vec![start];
+            let mut current = start;
+            while let Some(&(next, edge)) = neighbours[current].iter().find(|(_, e)| !used[*e]) {
+                used[edge] = true;
+                unused_degree[current] -= 1;
+                unused_degree[next] -= 1;
+                remaining -= 1;
+                stroke.push(next);
+                current = next;
+            }
+            position = self.vertices[current];
+            strokes.push(stroke);
+        }
+        strokes
+    }
+}
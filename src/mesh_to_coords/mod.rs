@@ -0,0 +1,3 @@
+mod mesh;
+pub mod request;
+pub mod wireframe;
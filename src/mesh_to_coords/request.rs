@@ -0,0 +1,114 @@
+use crate::mesh_to_coords::mesh::{Mesh, Vertex};
+use crate::mesh_to_coords::wireframe::Wireframe;
+
+pub struct Request<O: std::ops::Deref<Target = Vec<u8>>> {
+    pub wireframe: Wireframe,
+    pub obj: Option<O>,
+    pub rotation: (f32, f32, f32),
+    pub rotation_speed: (f32, f32, f32),
+    pub camera_distance: f32,
+    pub field_of_view: f32,
+    pub sample_rate: u32,
+    pub frame_rate: f32,
+    pub duration: f64,
+    pub result: Vec<(f32, f32)>,
+}
+
+// Rotates around the x axis, then y, then z. Angles are in radians.
+fn rotate([x, y, z]: Vertex, (ax, ay, az): (f32, f32, f32)) -> Vertex {
+    let (y, z) = (y * ax.cos() - z * ax.sin(), y * ax.sin() + z * ax.cos());
+    let (x, z) = (x * ay.cos() + z * ay.sin(), -x * ay.sin() + z * ay.cos());
+    let (x, y) = (x * az.cos() - y * az.sin(), x * az.sin() + y * az.cos());
+    [x, y, z]
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<O: std::ops::Deref<Target = Vec<u8>>> Request<O> {
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let mesh = match self.wireframe {
+            Wireframe::Cube => Mesh::cube(),
+            Wireframe::Icosahedron => Mesh::icosahedron(),
+            Wireframe::Torus => Mesh::torus(),
+            Wireframe::Obj => {
+                let Some(obj) = &self.obj else {
+                    return Err(anyhow::anyhow!("No obj mesh set, cannot render wireframe."));
+                };
+                Mesh::from_obj(std::str::from_utf8(obj)?)
+            }
+        };
+        if mesh.edges.is_empty() {
+            return Err(anyhow::anyhow!("The mesh has no edges to draw."));
+        }
+
+        let strokes = mesh.strokes();
+        let frame_rate = self.frame_rate.max(f32::EPSILON);
+        let samples_per_frame = ((self.sample_rate as f32 / frame_rate).round() as usize).max(1);
+        let frames = ((self.duration * frame_rate as f64).ceil() as usize).max(1);
+
+        self.result = Vec::with_capacity(frames * samples_per_frame);
+        for frame in 0..frames {
+            let time = frame as f32 / frame_rate;
+            let angles = (
+                (self.rotation.0 + self.rotation_speed.0 * time).to_radians(),
+                (self.rotation.1 + self.rotation_speed.1 * time).to_radians(),
+                (self.rotation.2 + self.rotation_speed.2 * time).to_radians(),
+            );
+            let projected: Vec<(f32, f32)> = mesh
+                .vertices
+                .iter()
+                .map(|vertex| self.project(rotate(*vertex, angles)))
+                .collect();
+            let drawing = Self::draw(&strokes, &projected, samples_per_frame);
+            self.result.extend(drawing);
+        }
+        Ok(())
+    }
+
+    // Perspective projection with the camera on the negative z axis looking at the center. A
+    // camera distance of 0 or less gives an orthographic projection.
+    fn project(&self, [x, y, z]: Vertex) -> (f32, f32) {
+        if self.camera_distance <= 0.0 {
+            return (x, -y);
+        }
+        let focal = 1.0 / (self.field_of_view.clamp(1.0, 179.0).to_radians() / 2.0).tan();
+        let depth = (z + self.camera_distance).max(0.01);
+        (focal * x / depth, -focal * y / depth)
+    }
+
+    // Spreads the samples of a frame evenly over the length of the strokes, so every edge is
+    // equally bright. The jump between two strokes takes a single sample.
+    fn draw(strokes: &[Vec<usize>], projected: &[(f32, f32)], samples: usize) -> Vec<(f32, f32)> {
+        let segment = |a: usize, b: usize| {
+            let ((x0, y0), (x1, y1)) = (projected[a], projected[b]);
+            ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt()
+        };
+        let total: f32 = strokes
+            .iter()
+            .flat_map(|stroke| stroke.windows(2).map(|w| segment(w[0], w[1])))
+            .sum();
+        let step = total / samples as f32;
+
+        let mut drawing = Vec::with_capacity(samples);
+        let mut walked = 0.0;
+        'strokes: for stroke in strokes {
+            for w in stroke.windows(2) {
+                let length = segment(w[0], w[1]);
+                let ((x0, y0), (x1, y1)) = (projected[w[0]], projected[w[1]]);
+                while walked <= length {
+                    if drawing.len() == samples {
+                        break 'strokes;
+                    }
+                    let t = if length > 0.0 { walked / length } else { 0.0 };
+                    drawing.push((x0 + t * (x1 - x0), y0 + t * (y1 - y0)));
+                    walked += step.max(f32::EPSILON);
+                }
+                walked -= length;
+            }
+        }
+
+        let last = drawing.last().copied().unwrap_or((0.0, 0.0));
+        drawing.resize(samples, last);
+        drawing
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// Wireframe enum
+/// Capture the built-in meshes and the uploaded obj mesh
+#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Wireframe {
+    #[default]
+    Cube,
+    Icosahedron,
+    Torus,
+    Obj,
+}
+
+/// Implement Display for Wireframe
+impl std::fmt::Display for Wireframe {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", <&str>::from(*self))
+    }
+}
+
+/// Implement From<Wireframe> for &str
+impl From<Wireframe> for &str {
+    fn from(wireframe: Wireframe) -> Self {
+        match wireframe {
+            Wireframe::Cube => "cube",
+            Wireframe::Icosahedron => "icosahedron",
+            Wireframe::Torus => "torus",
+            Wireframe::Obj => "obj",
+        }
+    }
+}
+
+/// Implement From<&str> for Wireframe
+impl TryFrom<&str> for Wireframe {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            _ if value.contains("cube") => Self::Cube,
+            _ if value.contains("ico") => Self::Icosahedron,
+            _ if value.contains("torus") => Self::Torus,
+            _ if value.contains("obj") => Self::Obj,
+            _ => return Err(()),
+        })
+    }
+}
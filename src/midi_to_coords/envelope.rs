@@ -0,0 +1,35 @@
+/// Attack, decay and release in seconds, the sustain level from 0.0 to 1.0.
+#[derive(Clone, Copy, Debug)]
+pub struct Envelope {
+    pub attack: f32,
+    pub decay: f32,
+    pub sustain: f32,
+    pub release: f32,
+}
+
+impl Envelope {
+    /// Level at a time after the note started, for a note that is held for `held` seconds.
+    pub fn level(&self, time: f32, held: f32) -> f32 {
+        if time < held {
+            return self.held_level(time);
+        }
+        let release = self.release.max(f32::EPSILON);
+        self.held_level(held) * (1.0 - (time - held) / release).max(0.0)
+    }
+
+    fn held_level(&self, time: f32) -> f32 {
+        let sustain = self.sustain.clamp(0.0, 1.0);
+        if time < self.attack {
+            time / self.attack
+        } else if time < self.attack + self.decay {
+            1.0 - (1.0 - sustain) * (time - self.attack) / self.decay
+        } else {
+            sustain
+        }
+    }
+
+    /// Seconds a note keeps sounding after it is released.
+    pub fn tail(&self) -> f32 {
+        self.release.max(0.0)
+    }
+}
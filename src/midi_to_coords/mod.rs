@@ -0,0 +1,3 @@
+pub mod envelope;
+mod notes;
+pub mod request;
@@ -0,0 +1,93 @@
+use midly::{MetaMessage, MidiMessage, Smf, Timing, TrackEventKind};
+use std::collections::HashMap;
+
+// Tempo of a file until its first tempo event, 120 beats per minute.
+const DEFAULT_TEMPO: f64 = 500_000.0;
+
+/// A note of the file with its start and length in seconds.
+#[derive(Clone, Copy, Debug)]
+pub struct Note {
+    pub key: u8,
+    pub velocity: u8,
+    pub start: f64,
+    pub length: f64,
+}
+
+/// Reads every note of a Standard MIDI File. The events of all tracks are merged, so tempo
+/// changes in the first track apply to the notes of the other tracks too.
+pub fn read_notes(midi: &[u8]) -> Result<Vec<Note>, anyhow::Error> {
+    let smf = Smf::parse(midi)?;
+
+    let mut events = vec![];
+    for track in &smf.tracks {
+        let mut tick = 0u64;
+        for event in track {
+            tick += event.delta.as_int() as u64;
+            events.push((tick, event.kind));
+        }
+    }
+    events.sort_by_key(|(tick, _)| *tick);
+
+    let mut tempo = DEFAULT_TEMPO;
+    let mut last_tick = 0;
+    let mut time = 0.0;
+    let mut sounding: HashMap<(u8, u8), Vec<(f64, u8)>> = HashMap::new();
+    let mut notes = vec![];
+
+    for (tick, kind) in events {
+        time += (tick - last_tick) as f64 * seconds_per_tick(smf.header.timing, tempo);
+        last_tick = tick;
+
+        match kind {
+            TrackEventKind::Meta(MetaMessage::Tempo(t)) => tempo = t.as_int() as f64,
+            TrackEventKind::Midi { channel, message } => {
+                let (key, velocity) = match message {
+                    MidiMessage::NoteOn { key, vel } => (key.as_int(), vel.as_int()),
+                    MidiMessage::NoteOff { key, .. } => (key.as_int(), 0),
+                    _ => continue,
+                };
+                let starts = sounding.entry((channel.as_int(), key)).or_default();
+                match velocity {
+                    0 => {
+                        if let Some((start, velocity)) = starts.pop() {
+                            notes.push(Note {
+                                key,
+                                velocity,
+                                start,
+                                length: time - start,
+                            });
+                        }
+                    }
+                    velocity => starts.push((time, velocity)),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Notes that are never released end with the file.
+    for ((_, key), starts) in sounding {
+        for (start, velocity) in starts {
+            notes.push(Note {
+                key,
+                velocity,
+                start,
+                length: time - start,
+            });
+        }
+    }
+
+    notes.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(notes)
+}
+
+fn seconds_per_tick(timing: Timing, tempo: f64) -> f64 {
+    match timing {
+        Timing::Metrical(ticks_per_beat) => {
+            tempo / 1_000_000.0 / ticks_per_beat.as_int().max(1) as f64
+        }
+        Timing::Timecode(fps, ticks_per_frame) => {
+            1.0 / (fps.as_f32() as f64 * ticks_per_frame.max(1) as f64)
+        }
+    }
+}
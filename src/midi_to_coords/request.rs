@@ -0,0 +1,91 @@
+use crate::coords_to_audio::pitch::{note_to_frequency, sample_at};
+use crate::midi_to_coords::envelope::Envelope;
+use crate::midi_to_coords::notes::{Note, read_notes};
+
+// The longest song that is rendered, in seconds. The whole song is held in memory twice, once as
+// coords and once as audio, so longer songs are refused instead of exhausting the memory.
+const MAX_DURATION: f64 = 120.0;
+
+pub struct Request<C, M>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+    M: std::ops::Deref<Target = Vec<u8>>,
+{
+    pub coords: C,
+    pub midi: M,
+    pub sample_rate: u32,
+    pub envelope: Envelope,
+    pub result: Vec<(f32, f32)>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<C, M> Request<C, M>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+    M: std::ops::Deref<Target = Vec<u8>>,
+{
+    // Every note draws the coords at the pitch of the note, scaled by its velocity and the
+    // envelope. Overlapping notes are added up, when the mix goes past full scale the whole
+    // clip is turned down, so chords keep their shape instead of clipping.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        if self.coords.is_empty() {
+            return Err(anyhow::anyhow!("There are no coords to play."));
+        }
+        let notes = read_notes(&self.midi)?;
+        let sample_rate = self.sample_rate.max(1) as f64;
+        let tail = self.envelope.tail() as f64;
+        let end = self.end(&notes)?;
+
+        self.result = vec![(0.0, 0.0); (end * sample_rate).ceil() as usize];
+        for note in notes {
+            let frequency = note_to_frequency(note.key) as f64;
+            let size = note.velocity as f32 / 127.0;
+            let first = (note.start * sample_rate).round() as usize;
+            let samples = ((note.length + tail) * sample_rate).round() as usize;
+            for i in 0..samples {
+                let Some(mixed) = self.result.get_mut(first + i) else {
+                    break;
+                };
+                let time = i as f64 / sample_rate;
+                let gain = size * self.envelope.level(time as f32, note.length as f32);
+                let (x, y) = sample_at(&self.coords, time * frequency);
+                *mixed = (mixed.0 + x * gain, mixed.1 + y * gain);
+            }
+        }
+
+        let peak = self
+            .result
+            .iter()
+            .fold(0.0f32, |peak, (x, y)| peak.max(x.abs()).max(y.abs()));
+        if peak > 1.0 {
+            self.result
+                .iter_mut()
+                .for_each(|(x, y)| (*x, *y) = (*x / peak, *y / peak));
+        }
+        Ok(())
+    }
+
+    /// The length of the rendered song in seconds, checked before the song is rendered so a
+    /// song that is too long fails right away.
+    pub fn duration(&self) -> Result<f64, anyhow::Error> {
+        self.end(&read_notes(&self.midi)?)
+    }
+
+    // The song ends when the release of the last note has faded out.
+    fn end(&self, notes: &[Note]) -> Result<f64, anyhow::Error> {
+        let tail = self.envelope.tail() as f64;
+        let end = notes
+            .iter()
+            .map(|note| note.start + note.length + tail)
+            .fold(0.0, f64::max);
+        if !end.is_finite() || end > MAX_DURATION {
+            return Err(anyhow::anyhow!(
+                "The song lasts {:.0} seconds, at most {} seconds can be rendered.",
+                end,
+                MAX_DURATION
+            ));
+        }
+        Ok(end)
+    }
+}
@@ -49,4 +49,94 @@ impl JsInterface {
         info!("processing image ok");
         Ok(())
     }
+
+    #[wasm_bindgen]
+    pub async fn process_svg_to_coords(&mut self) -> Result<(), JsValue> {
+        info!("processing svg to coords");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::SvgToCoords,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_frames_to_coords(&mut self) -> Result<(), JsValue> {
+        info!("processing frames to coords");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::FramesToCoords,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_image_to_automated_coords(&mut self) -> Result<(), JsValue> {
+        info!("processing image to automated coords");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::ImageToAutomatedCoords,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_text_to_coords(&mut self) -> Result<(), JsValue> {
+        info!("processing text to coords");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::TextToCoords,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_shape_to_coords(&mut self) -> Result<(), JsValue> {
+        info!("processing shape to coords");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::ShapeToCoords,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_mesh_to_coords(&mut self) -> Result<(), JsValue> {
+        info!("processing mesh to coords");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::MeshToCoords,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_scene_to_coords(&mut self) -> Result<(), JsValue> {
+        info!("processing scene to coords");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::SceneToCoords,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_midi_to_audio(&mut self) -> Result<(), JsValue> {
+        info!("processing midi to audio");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::MidiToAudio,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn process_image_to_spectrogram(&mut self) -> Result<(), JsValue> {
+        info!("processing image to spectrogram");
+        let args = processing::ProcessArgs {
+            request: ProcessRequest::ImageToSpectrogram,
+        };
+        self.inner.process(args).await.map_err(to_js)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,66 @@
+use crate::image_to_coords::method::Method;
+use crate::shape_to_coords::shape::Shape;
+use serde::{Deserialize, Serialize};
+
+/// Where the coords of a layer come from. Image layers trace one of the scene images, text and
+/// shape layers use the text and shape settings with their own text or shape.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Source {
+    Image {
+        method: Method,
+        #[serde(default)]
+        image: usize,
+    },
+    Text {
+        text: String,
+    },
+    Shape {
+        shape: Shape,
+    },
+}
+
+/// Scales, then rotates (in degrees) around the center, then translates the coords of a layer.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Transform {
+    pub translate: (f32, f32),
+    pub scale: f32,
+    pub rotate: f32,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform {
+            translate: (0.0, 0.0),
+            scale: 1.0,
+            rotate: 0.0,
+        }
+    }
+}
+
+impl Transform {
+    pub fn apply(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (sin, cos) = self.rotate.to_radians().sin_cos();
+        let (x, y) = (x * self.scale, y * self.scale);
+        (
+            x * cos - y * sin + self.translate.0,
+            x * sin + y * cos + self.translate.1,
+        )
+    }
+}
+
+fn default_share() -> f32 {
+    1.0
+}
+
+/// A single part of a scene. The share is the part of the sample budget the layer gets,
+/// relative to the shares of the other layers.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Layer {
+    pub source: Source,
+    #[serde(default)]
+    pub transform: Transform,
+    #[serde(default = "default_share")]
+    pub share: f32,
+}
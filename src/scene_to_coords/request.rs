@@ -0,0 +1,78 @@
+use crate::backend::process_request::{
+    image_to_coords_request, shape_to_coords_request, text_to_coords_request,
+};
+use crate::backend::settings::Settings;
+use crate::image_to_coords::processing_utils::resample_coords;
+use crate::scene_to_coords::layer::{Layer, Source};
+use image::GrayImage;
+use std::sync::Arc;
+
+pub struct Request<L: std::ops::Deref<Target = Vec<Layer>>> {
+    pub layers: L,
+    pub images: Arc<Vec<Arc<GrayImage>>>,
+    pub font: Option<Arc<Vec<u8>>>,
+    pub settings: Settings,
+    pub scene_samples: u32,
+    pub result: Vec<(f32, f32)>,
+    pub contour_starts: Vec<usize>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<L: std::ops::Deref<Target = Vec<Layer>>> Request<L> {
+    // Every layer is drawn in turn, resampled to its share of the scene, so all layers are on
+    // screen at once when the scene loops.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let total_share: f32 = self.layers.iter().map(|l| l.share.max(0.0)).sum();
+        if total_share <= 0.0 {
+            return Err(anyhow::anyhow!("The scene has no layers to draw."));
+        }
+
+        self.result = Vec::with_capacity(self.scene_samples as usize);
+        self.contour_starts = Vec::with_capacity(self.layers.len());
+        for layer in self.layers.iter() {
+            let samples =
+                (layer.share.max(0.0) / total_share * self.scene_samples as f32).round() as usize;
+            let coords = self.trace(&layer.source)?;
+            if samples == 0 || coords.is_empty() {
+                continue;
+            }
+
+            self.contour_starts.push(self.result.len());
+            self.result.extend(
+                resample_coords(&coords, samples)
+                    .into_iter()
+                    .map(|point| layer.transform.apply(point)),
+            );
+        }
+        Ok(())
+    }
+
+    // Runs the source of a layer through the same request its own process would use.
+    fn trace(&self, source: &Source) -> Result<Vec<(f32, f32)>, anyhow::Error> {
+        let mut settings = self.settings.clone();
+        match source {
+            Source::Image { method, image } => {
+                let Some(image) = self.images.get(*image) else {
+                    return Err(anyhow::anyhow!("The scene has no image {}.", image));
+                };
+                settings.method = *method;
+                settings.size = image.width();
+                let mut request = image_to_coords_request(&settings, &**image);
+                request.process();
+                Ok(request.result)
+            }
+            Source::Text { text } => {
+                let mut request = text_to_coords_request(&settings, text, self.font.clone());
+                request.process()?;
+                Ok(request.result)
+            }
+            Source::Shape { shape } => {
+                settings.shape = *shape;
+                let mut request = shape_to_coords_request(&settings);
+                request.process();
+                Ok(request.result)
+            }
+        }
+    }
+}
@@ -1,9 +1,15 @@
 use crate::JsInterface;
+use crate::automation::curve::Automation;
 use crate::backend::settings::*;
 use crate::backend::state;
+use crate::coords_to_audio::pitch;
 use crate::image_to_coords::method::Method;
+use crate::mesh_to_coords::wireframe::Wireframe;
+use crate::scene_to_coords::layer::Layer;
+use crate::shape_to_coords::shape::Shape;
 use crate::to_js;
 use crate::utils;
+use image::{GrayImage, imageops::FilterType};
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
@@ -137,6 +143,33 @@ impl JsInterface {
         Ok(())
     }
 
+    #[wasm_bindgen]
+    pub async fn set_outer_only(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetOuterOnly(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_reverse_holes(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetReverseHoles(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_depth_first(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetDepthFirst(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
     #[wasm_bindgen]
     pub async fn set_scramble(&mut self, value: bool) -> Result<(), JsValue> {
         self.inner.settings(SetScramble(value)).await.map_err(to_js);
@@ -284,24 +317,1014 @@ impl JsInterface {
     }
 
     #[wasm_bindgen]
-    pub async fn audio_to_backend(&mut self, audio_data: Vec<u8>) -> Result<(), JsValue> {
+    pub async fn set_stipple_points(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid stipple points: {}", e)))?;
         self.inner
-            .state(state::SetAudio(Arc::new(audio_data)))
+            .settings(SetStipplePoints(val))
             .await
             .map_err(to_js)?;
         Ok(())
     }
 
     #[wasm_bindgen]
-    pub async fn image_to_backend(&mut self, image_data: &[u8]) -> Result<(), JsValue> {
-        let image = image::load_from_memory(image_data).map_err(to_js)?;
-        let gray_image = image.to_luma8();
-        let (result, size) = utils::convert_to_canvas_size(&gray_image);
+    pub async fn set_stipple_iterations(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid stipple iterations: {}", e)))?;
         self.inner
-            .state(state::SetImage(Arc::new(result)))
+            .settings(SetStippleIterations(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_tsp_points(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid tsp points: {}", e)))?;
+        self.inner
+            .settings(SetTspPoints(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_tsp_iterations(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid tsp iterations: {}", e)))?;
+        self.inner
+            .settings(SetTspIterations(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spiral_spacing(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spiral spacing: {}", e)))?;
+        self.inner
+            .settings(SetSpiralSpacing(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spiral_start_angle(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spiral start angle: {}", e)))?;
+        self.inner
+            .settings(SetSpiralStartAngle(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spiral_type(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spiral type: {}", e)))?;
+        self.inner
+            .settings(SetSpiralType(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_min_contour_length(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid minimum contour length: {}", e)))?;
+        self.inner
+            .settings(SetMinContourLength(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_min_contour_area(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid minimum contour area: {}", e)))?;
+        self.inner
+            .settings(SetMinContourArea(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_max_contours(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid maximum contours: {}", e)))?;
+        self.inner
+            .settings(SetMaxContours(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_speckle_size(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid speckle size: {}", e)))?;
+        self.inner
+            .settings(SetSpeckleSize(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_svg_tolerance(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid svg tolerance: {}", e)))?;
+        self.inner
+            .settings(SetSvgTolerance(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_point_rate(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid point rate: {}", e)))?;
+        self.inner
+            .settings(SetPointRate(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_ilda_format(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid ilda format: {}", e)))?;
+        self.inner
+            .settings(SetIldaFormat(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_contour_colors(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetContourColors(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_plot_size(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid plot size: {}", e)))?;
+        self.inner.settings(SetPlotSize(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_plot_origin(&mut self, x: JsValue, y: JsValue) -> Result<(), JsValue> {
+        let x_val: f32 =
+            from_value(x).map_err(|e| JsValue::from_str(&format!("Invalid f32 for x: {}", e)))?;
+        let y_val: f32 =
+            from_value(y).map_err(|e| JsValue::from_str(&format!("Invalid f32 for y: {}", e)))?;
+        self.inner
+            .settings(SetPlotOrigin((x_val, y_val)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_feed_rate(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid feed rate: {}", e)))?;
+        self.inner.settings(SetFeedRate(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_frame_rate(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid frame rate: {}", e)))?;
+        self.inner
+            .settings(SetFrameRate(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_frame_repeat(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid frame repeat: {}", e)))?;
+        self.inner
+            .settings(SetFrameRepeat(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_coherent_frames(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetCoherentFrames(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_automation(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let automation: Automation = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid automation: {}", e)))?;
+        // Automation without keyframes is removed, so processing keeps its fast paths.
+        let automation = Some(automation)
+            .filter(|automation| !automation.is_empty())
+            .map(|automation| Arc::new(automation.sorted()));
+        self.inner
+            .state(state::SetAutomation(automation))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_text_size(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid text size: {}", e)))?;
+        self.inner.settings(SetTextSize(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_text_align(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid text align: {}", e)))?;
+        self.inner
+            .settings(SetTextAlign(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_line_spacing(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid line spacing: {}", e)))?;
+        self.inner
+            .settings(SetLineSpacing(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_outline_font(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetOutlineFont(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_shape(&mut self, value: &str) -> Result<(), JsValue> {
+        let shape = Shape::try_from(value).map_err(|_| JsValue::from_str("Unknown shape"))?;
+        self.inner.settings(SetShape(shape)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_shape_points(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid shape points: {}", e)))?;
+        self.inner
+            .settings(SetShapePoints(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_lissajous_ratio(&mut self, a: JsValue, b: JsValue) -> Result<(), JsValue> {
+        let a_val: u32 = from_value(a)
+            .map_err(|e| JsValue::from_str(&format!("Invalid lissajous ratio: {}", e)))?;
+        let b_val: u32 = from_value(b)
+            .map_err(|e| JsValue::from_str(&format!("Invalid lissajous ratio: {}", e)))?;
+        self.inner
+            .settings(SetLissajousRatio((a_val, b_val)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_lissajous_phase(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid lissajous phase: {}", e)))?;
+        self.inner
+            .settings(SetLissajousPhase(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_rose_ratio(&mut self, a: JsValue, b: JsValue) -> Result<(), JsValue> {
+        let a_val: u32 =
+            from_value(a).map_err(|e| JsValue::from_str(&format!("Invalid rose ratio: {}", e)))?;
+        let b_val: u32 =
+            from_value(b).map_err(|e| JsValue::from_str(&format!("Invalid rose ratio: {}", e)))?;
+        self.inner
+            .settings(SetRoseRatio((a_val, b_val)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_trochoid_radii(&mut self, a: JsValue, b: JsValue) -> Result<(), JsValue> {
+        let a_val: u32 = from_value(a)
+            .map_err(|e| JsValue::from_str(&format!("Invalid trochoid radius: {}", e)))?;
+        let b_val: u32 = from_value(b)
+            .map_err(|e| JsValue::from_str(&format!("Invalid trochoid radius: {}", e)))?;
+        self.inner
+            .settings(SetTrochoidRadii((a_val, b_val)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_trochoid_offset(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid trochoid offset: {}", e)))?;
+        self.inner
+            .settings(SetTrochoidOffset(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_polygon_sides(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid polygon sides: {}", e)))?;
+        self.inner
+            .settings(SetPolygonSides(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_star_ratio(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid star ratio: {}", e)))?;
+        self.inner
+            .settings(SetStarRatio(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_superformula_m(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid superformula m: {}", e)))?;
+        self.inner
+            .settings(SetSuperformulaM(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_superformula_n(
+        &mut self,
+        n1: JsValue,
+        n2: JsValue,
+        n3: JsValue,
+    ) -> Result<(), JsValue> {
+        let parse = |value: JsValue| -> Result<f32, JsValue> {
+            from_value(value)
+                .map_err(|e| JsValue::from_str(&format!("Invalid superformula n: {}", e)))
+        };
+        let n = (parse(n1)?, parse(n2)?, parse(n3)?);
+        self.inner
+            .settings(SetSuperformulaN(n))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_wireframe(&mut self, value: &str) -> Result<(), JsValue> {
+        let wireframe =
+            Wireframe::try_from(value).map_err(|_| JsValue::from_str("Unknown wireframe"))?;
+        self.inner
+            .settings(SetWireframe(wireframe))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_wireframe_rotation(
+        &mut self,
+        x: JsValue,
+        y: JsValue,
+        z: JsValue,
+    ) -> Result<(), JsValue> {
+        let parse = |value: JsValue| -> Result<f32, JsValue> {
+            from_value(value).map_err(|e| JsValue::from_str(&format!("Invalid rotation: {}", e)))
+        };
+        let value = (parse(x)?, parse(y)?, parse(z)?);
+        self.inner
+            .settings(SetWireframeRotation(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_rotation_speed(
+        &mut self,
+        x: JsValue,
+        y: JsValue,
+        z: JsValue,
+    ) -> Result<(), JsValue> {
+        let parse = |value: JsValue| -> Result<f32, JsValue> {
+            from_value(value)
+                .map_err(|e| JsValue::from_str(&format!("Invalid rotation speed: {}", e)))
+        };
+        let value = (parse(x)?, parse(y)?, parse(z)?);
+        self.inner
+            .settings(SetRotationSpeed(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_camera_distance(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid camera distance: {}", e)))?;
+        self.inner
+            .settings(SetCameraDistance(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_field_of_view(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid field of view: {}", e)))?;
+        self.inner
+            .settings(SetFieldOfView(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_scene_samples(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid scene samples: {}", e)))?;
+        self.inner
+            .settings(SetSceneSamples(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_scene(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let layers: Vec<Layer> =
+            from_value(value).map_err(|e| JsValue::from_str(&format!("Invalid scene: {}", e)))?;
+        self.inner
+            .state(state::SetScene(Arc::new(layers)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_rotate(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform rotate: {}", e)))?;
+        self.inner
+            .settings(SetTransformRotate(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_scale_x(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform scale x: {}", e)))?;
+        self.inner
+            .settings(SetTransformScaleX(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_scale_y(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform scale y: {}", e)))?;
+        self.inner
+            .settings(SetTransformScaleY(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_translate_x(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform translate x: {}", e)))?;
+        self.inner
+            .settings(SetTransformTranslateX(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_translate_y(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform translate y: {}", e)))?;
+        self.inner
+            .settings(SetTransformTranslateY(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_shear_x(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform shear x: {}", e)))?;
+        self.inner
+            .settings(SetTransformShearX(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_shear_y(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform shear y: {}", e)))?;
+        self.inner
+            .settings(SetTransformShearY(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_mirror_x(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetMirrorX(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_mirror_y(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetMirrorY(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_fisheye(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 =
+            from_value(value).map_err(|e| JsValue::from_str(&format!("Invalid fisheye: {}", e)))?;
+        self.inner.settings(SetFisheye(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_swirl(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 =
+            from_value(value).map_err(|e| JsValue::from_str(&format!("Invalid swirl: {}", e)))?;
+        self.inner.settings(SetSwirl(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_wave_amplitude(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid wave amplitude: {}", e)))?;
+        self.inner
+            .settings(SetWaveAmplitude(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_wave_frequency(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid wave frequency: {}", e)))?;
+        self.inner
+            .settings(SetWaveFrequency(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_transform_spin(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid transform spin: {}", e)))?;
+        self.inner
+            .settings(SetTransformSpin(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_wave_speed(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid wave speed: {}", e)))?;
+        self.inner
+            .settings(SetWaveSpeed(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_remove_dc(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetRemoveDc(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_high_pass(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid high pass: {}", e)))?;
+        self.inner.settings(SetHighPass(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_low_pass(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid low pass: {}", e)))?;
+        self.inner.settings(SetLowPass(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_ring_mod_frequency(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid ring mod frequency: {}", e)))?;
+        self.inner
+            .settings(SetRingModFrequency(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_ring_mod_amount(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid ring mod amount: {}", e)))?;
+        self.inner
+            .settings(SetRingModAmount(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_stereo_rotation(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid stereo rotation: {}", e)))?;
+        self.inner
+            .settings(SetStereoRotation(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_noise_amount(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid noise amount: {}", e)))?;
+        self.inner
+            .settings(SetNoiseAmount(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_noise_seed(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid noise seed: {}", e)))?;
+        self.inner
+            .settings(SetNoiseSeed(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_soft_clip(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid soft clip: {}", e)))?;
+        self.inner.settings(SetSoftClip(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_pitch_lock(&mut self, value: bool) -> Result<(), JsValue> {
+        self.inner
+            .settings(SetPitchLock(value))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_pitch_frequency(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid pitch frequency: {}", e)))?;
+        self.inner
+            .settings(SetPitchFrequency(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    // Picks the pitch by MIDI note, the note is stored as its frequency.
+    #[wasm_bindgen]
+    pub async fn set_pitch_note(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u8 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid pitch note: {}", e)))?;
+        self.inner
+            .settings(SetPitchFrequency(pitch::note_to_frequency(val.min(127))))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_synth_attack(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid synth attack: {}", e)))?;
+        self.inner
+            .settings(SetSynthAttack(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_synth_decay(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid synth decay: {}", e)))?;
+        self.inner
+            .settings(SetSynthDecay(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_synth_sustain(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid synth sustain: {}", e)))?;
+        self.inner
+            .settings(SetSynthSustain(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_synth_release(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid synth release: {}", e)))?;
+        self.inner
+            .settings(SetSynthRelease(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spectrogram_fft_size(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: u32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spectrogram fft size: {}", e)))?;
+        self.inner
+            .settings(SetSpectrogramFftSize(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spectrogram_duration(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spectrogram duration: {}", e)))?;
+        self.inner
+            .settings(SetSpectrogramDuration(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spectrogram_min_frequency(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spectrogram min frequency: {}", e)))?;
+        self.inner
+            .settings(SetSpectrogramMinFrequency(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spectrogram_max_frequency(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spectrogram max frequency: {}", e)))?;
+        self.inner
+            .settings(SetSpectrogramMaxFrequency(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_spectrogram_level(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid spectrogram level: {}", e)))?;
+        self.inner
+            .settings(SetSpectrogramLevel(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_render_fps(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid render fps: {}", e)))?;
+        self.inner
+            .settings(SetRenderFps(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_render_duration(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 = from_value(value)
+            .map_err(|e| JsValue::from_str(&format!("Invalid render duration: {}", e)))?;
+        self.inner
+            .settings(SetRenderDuration(val))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn set_glow(&mut self, value: JsValue) -> Result<(), JsValue> {
+        let val: f32 =
+            from_value(value).map_err(|e| JsValue::from_str(&format!("Invalid glow: {}", e)))?;
+        self.inner.settings(SetGlow(val)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn audio_to_backend(&mut self, audio_data: Vec<u8>) -> Result<(), JsValue> {
+        self.inner
+            .state(state::SetAudio(Arc::new(audio_data)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn image_to_backend(&mut self, image_data: &[u8]) -> Result<(), JsValue> {
+        let image = image::load_from_memory(image_data).map_err(to_js)?;
+        let gray_image = image.to_luma8();
+        let (result, size) = utils::convert_to_canvas_size(&gray_image);
+        self.inner
+            .state(state::SetImage(Arc::new(result)))
+            .await
+            .map_err(to_js)?;
+        self.inner.settings(SetSize(size)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn svg_to_backend(&mut self, svg_data: Vec<u8>) -> Result<(), JsValue> {
+        self.inner
+            .state(state::SetSvg(Arc::new(svg_data)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    // Both gifs and numbered image sequences end up as one list of frames.
+    #[wasm_bindgen]
+    pub async fn frames_to_backend(&mut self, files: js_sys::Array) -> Result<(), JsValue> {
+        let frames = utils::load_images(&files).map_err(to_js)?;
+        let Some(first) = frames.first() else {
+            return Err(JsValue::from_str("No frames found"));
+        };
+        // Every frame is stretched to the canvas of the first frame, the tracer expects one size
+        // for the whole sequence.
+        let size = first.width().max(first.height());
+        let frames: Vec<GrayImage> = frames
+            .iter()
+            .map(|frame| image::imageops::resize(frame, size, size, FilterType::Lanczos3))
+            .collect();
+        self.inner
+            .state(state::SetFrames(Arc::new(frames)))
+            .await
+            .map_err(to_js)?;
+        self.inner.settings(SetSize(size)).await.map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn text_to_backend(&mut self, text: String) -> Result<(), JsValue> {
+        self.inner
+            .state(state::SetText(Arc::new(text)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    // Fonts are checked when they are uploaded, so a broken font fails here and not while
+    // processing.
+    #[wasm_bindgen]
+    pub async fn font_to_backend(&mut self, font_data: Vec<u8>) -> Result<(), JsValue> {
+        ttf_parser::Face::parse(&font_data, 0).map_err(to_js)?;
+        self.inner
+            .state(state::SetFont(Arc::new(font_data)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn mesh_to_backend(&mut self, obj_data: Vec<u8>) -> Result<(), JsValue> {
+        self.inner
+            .state(state::SetMesh(Arc::new(obj_data)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    // Scene images keep their own size, image layers trace them one by one.
+    #[wasm_bindgen]
+    pub async fn scene_images_to_backend(&mut self, files: js_sys::Array) -> Result<(), JsValue> {
+        let images: Vec<Arc<GrayImage>> = utils::load_images(&files)
+            .map_err(to_js)?
+            .iter()
+            .map(|image| Arc::new(utils::convert_to_canvas_size(image).0))
+            .collect();
+        self.inner
+            .state(state::SetSceneImages(Arc::new(images)))
+            .await
+            .map_err(to_js)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub async fn midi_to_backend(&mut self, midi_data: Vec<u8>) -> Result<(), JsValue> {
+        midly::Smf::parse(&midi_data).map_err(to_js)?;
+        self.inner
+            .state(state::SetMidi(Arc::new(midi_data)))
             .await
             .map_err(to_js)?;
-        self.inner.settings(SetSize(size)).await.map_err(to_js)?;
         Ok(())
     }
 }
@@ -0,0 +1,183 @@
+use crate::shape_to_coords::shape::Shape;
+use std::f32::consts::{PI, TAU};
+
+pub struct Request {
+    pub shape: Shape,
+    pub shape_points: u32,
+    pub lissajous_ratio: (u32, u32),
+    pub lissajous_phase: f32,
+    pub rose_ratio: (u32, u32),
+    pub trochoid_radii: (u32, u32),
+    pub trochoid_offset: f32,
+    pub polygon_sides: u32,
+    pub star_ratio: f32,
+    pub superformula_m: u32,
+    pub superformula_n: (f32, f32, f32),
+    pub result: Vec<(f32, f32)>,
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl Request {
+    // Shape dispatcher
+    pub fn process(&mut self) {
+        let points = match self.shape {
+            Shape::Lissajous => self.sample(TAU, Self::lissajous),
+            Shape::Rose => self.sample(self.rose_period(), Self::rose),
+            Shape::Hypotrochoid => self.sample(self.trochoid_period(), Self::hypotrochoid),
+            Shape::Epitrochoid => self.sample(self.trochoid_period(), Self::epitrochoid),
+            Shape::Polygon => self.polygon(),
+            Shape::Superformula => self.sample(self.superformula_period(), Self::superformula),
+        };
+        self.result = Self::normalize(points);
+    }
+
+    // Samples one full period of a curve. The end of the period is left out, it is the start of
+    // the next loop, so the coords repeat without a seam.
+    fn sample(&self, period: f32, curve: fn(&Self, f32) -> (f32, f32)) -> Vec<(f32, f32)> {
+        let amount = self.shape_points.max(1);
+        (0..amount)
+            .map(|i| curve(self, i as f32 / amount as f32 * period))
+            .collect()
+    }
+
+    // Scales the shape to fill [-1.0, 1.0] with y pointing downwards.
+    fn normalize(points: Vec<(f32, f32)>) -> Vec<(f32, f32)> {
+        let extent = points
+            .iter()
+            .fold(0.0f32, |max, (x, y)| max.max(x.abs()).max(y.abs()))
+            .max(f32::EPSILON);
+        points
+            .into_iter()
+            .map(|(x, y)| (x / extent, -y / extent))
+            .collect()
+    }
+
+    // Lissajous figure, the frequency ratio sets the amount of lobes and the phase turns it.
+    fn lissajous(&self, t: f32) -> (f32, f32) {
+        let (a, b) = self.lissajous_ratio;
+        let phase = self.lissajous_phase.to_radians();
+        ((a as f32 * t + phase).sin(), (b as f32 * t).sin())
+    }
+
+    // A rose with petals set by n / d closes after pi * d when both are odd, or 2 pi * d.
+    fn rose_period(&self) -> f32 {
+        let (n, d) = self.rose_ratio;
+        let divisor = gcd(n, d).max(1);
+        let (n, d) = (n / divisor, (d / divisor).max(1));
+        match (n * d) % 2 {
+            1 => PI * d as f32,
+            _ => TAU * d as f32,
+        }
+    }
+
+    fn rose(&self, t: f32) -> (f32, f32) {
+        let (n, d) = self.rose_ratio;
+        let r = (n as f32 / d.max(1) as f32 * t).cos();
+        (r * t.cos(), r * t.sin())
+    }
+
+    // Spirograph curves close once the rolling circle is back at its start, after
+    // 2 pi * r / gcd(R, r).
+    fn trochoid_period(&self) -> f32 {
+        let (fixed, rolling) = self.trochoid_radii;
+        let rolling = rolling.max(1);
+        TAU * (rolling / gcd(fixed, rolling).max(1)) as f32
+    }
+
+    fn hypotrochoid(&self, t: f32) -> (f32, f32) {
+        let (fixed, rolling) = self.trochoid_radii;
+        let (fixed, rolling) = (fixed as f32, rolling.max(1) as f32);
+        let d = self.trochoid_offset;
+        let k = (fixed - rolling) / rolling;
+        (
+            (fixed - rolling) * t.cos() + d * (k * t).cos(),
+            (fixed - rolling) * t.sin() - d * (k * t).sin(),
+        )
+    }
+
+    fn epitrochoid(&self, t: f32) -> (f32, f32) {
+        let (fixed, rolling) = self.trochoid_radii;
+        let (fixed, rolling) = (fixed as f32, rolling.max(1) as f32);
+        let d = self.trochoid_offset;
+        let k = (fixed + rolling) / rolling;
+        (
+            (fixed + rolling) * t.cos() - d * (k * t).cos(),
+            (fixed + rolling) * t.sin() - d * (k * t).sin(),
+        )
+    }
+
+    // Regular polygon, or a star when the inner radius is smaller than the outer one. Points are
+    // spread by length, so every side is equally bright.
+    fn polygon(&self) -> Vec<(f32, f32)> {
+        let sides = self.polygon_sides.max(3) as usize;
+        let star = (self.star_ratio - 1.0).abs() > f32::EPSILON;
+        let corners: Vec<(f32, f32)> = match star {
+            true => (0..sides * 2)
+                .map(|i| {
+                    let radius = if i % 2 == 0 { 1.0 } else { self.star_ratio };
+                    let angle = PI / 2.0 + i as f32 * PI / sides as f32;
+                    (radius * angle.cos(), radius * angle.sin())
+                })
+                .collect(),
+            false => (0..sides)
+                .map(|i| {
+                    let angle = PI / 2.0 + i as f32 * TAU / sides as f32;
+                    (angle.cos(), angle.sin())
+                })
+                .collect(),
+        };
+
+        let n = corners.len();
+        let lengths: Vec<f32> = (0..n)
+            .map(|i| {
+                let (a, b) = (corners[i], corners[(i + 1) % n]);
+                ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+            })
+            .collect();
+        let perimeter: f32 = lengths.iter().sum();
+
+        let amount = self.shape_points.max(1);
+        let mut edge = 0;
+        let mut walked = 0.0;
+        (0..amount)
+            .map(|i| {
+                let distance = i as f32 / amount as f32 * perimeter;
+                while edge < n - 1 && distance > walked + lengths[edge] {
+                    walked += lengths[edge];
+                    edge += 1;
+                }
+                let ((x0, y0), (x1, y1)) = (corners[edge], corners[(edge + 1) % n]);
+                let t = ((distance - walked) / lengths[edge].max(f32::EPSILON)).clamp(0.0, 1.0);
+                (x0 + t * (x1 - x0), y0 + t * (y1 - y0))
+            })
+            .collect()
+    }
+
+    // The superformula repeats after 2 pi for an even m, odd m need a second turn.
+    fn superformula_period(&self) -> f32 {
+        match self.superformula_m % 2 {
+            0 => TAU,
+            _ => 2.0 * TAU,
+        }
+    }
+
+    fn superformula(&self, t: f32) -> (f32, f32) {
+        let m = self.superformula_m as f32;
+        let (n1, n2, n3) = self.superformula_n;
+        let sum = (m * t / 4.0).cos().abs().powf(n2) + (m * t / 4.0).sin().abs().powf(n3);
+        let r = match sum > 0.0 && n1 != 0.0 {
+            true => sum.powf(-1.0 / n1),
+            false => 0.0,
+        };
+        let r = if r.is_finite() { r } else { 0.0 };
+        (r * t.cos(), r * t.sin())
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// Shape enum
+/// Capture the different shapes that can be generated without an input
+#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Shape {
+    #[default]
+    Lissajous,
+    Rose,
+    Hypotrochoid,
+    Epitrochoid,
+    Polygon,
+    Superformula,
+}
+
+/// Implement Display for Shape
+impl std::fmt::Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", <&str>::from(*self))
+    }
+}
+
+/// Implement From<Shape> for &str
+impl From<Shape> for &str {
+    fn from(shape: Shape) -> Self {
+        match shape {
+            Shape::Lissajous => "lissajous",
+            Shape::Rose => "rose",
+            Shape::Hypotrochoid => "hypotrochoid",
+            Shape::Epitrochoid => "epitrochoid",
+            Shape::Polygon => "polygon",
+            Shape::Superformula => "superformula",
+        }
+    }
+}
+
+/// Implement From<&str> for Shape
+impl TryFrom<&str> for Shape {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            _ if value.contains("lissajous") => Self::Lissajous,
+            _ if value.contains("rose") => Self::Rose,
+            _ if value.contains("hypo") => Self::Hypotrochoid,
+            _ if value.contains("epi") => Self::Epitrochoid,
+            _ if value.contains("polygon") || value.contains("star") => Self::Polygon,
+            _ if value.contains("superformula") => Self::Superformula,
+            _ => return Err(()),
+        })
+    }
+}
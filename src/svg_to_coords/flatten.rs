@@ -0,0 +1,129 @@
+use svgtypes::Transform;
+
+pub type Point = (f64, f64);
+
+// Control point distance for approximating a quarter ellipse with a cubic bezier.
+const KAPPA: f64 = 0.552_284_75;
+
+// Subdividing a curve further than this gains nothing visible.
+const MAX_DEPTH: u32 = 16;
+
+pub fn apply(ts: &Transform, (x, y): Point) -> Point {
+    (ts.a * x + ts.c * y + ts.e, ts.b * x + ts.d * y + ts.f)
+}
+
+// Combined transform that first applies `inner` and then `outer`.
+pub fn combine(outer: &Transform, inner: &Transform) -> Transform {
+    Transform::new(
+        outer.a * inner.a + outer.c * inner.b,
+        outer.b * inner.a + outer.d * inner.b,
+        outer.a * inner.c + outer.c * inner.d,
+        outer.b * inner.c + outer.d * inner.d,
+        outer.a * inner.e + outer.c * inner.f + outer.e,
+        outer.b * inner.e + outer.d * inner.f + outer.f,
+    )
+}
+
+fn distance_to_line(p: Point, a: Point, b: Point) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length == 0.0 {
+        return ((p.0 - a.0).powi(2) + (p.1 - a.1).powi(2)).sqrt();
+    }
+    ((p.0 - a.0) * dy - (p.1 - a.1) * dx).abs() / length
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+/// Flattens a cubic bezier into line segments that stay within `tolerance` of the curve. The
+/// start point is expected to already be in `out`.
+pub fn cubic(p0: Point, p1: Point, p2: Point, p3: Point, tolerance: f64, out: &mut Vec<Point>) {
+    subdivide(p0, p1, p2, p3, tolerance, 0, out);
+}
+
+fn subdivide(
+    p0: Point,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    tolerance: f64,
+    depth: u32,
+    out: &mut Vec<Point>,
+) {
+    let flat = distance_to_line(p1, p0, p3).max(distance_to_line(p2, p0, p3)) <= tolerance;
+    if flat || depth >= MAX_DEPTH {
+        out.push(p3);
+        return;
+    }
+
+    // De Casteljau split in the middle of the curve.
+    let p01 = midpoint(p0, p1);
+    let p12 = midpoint(p1, p2);
+    let p23 = midpoint(p2, p3);
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+    let center = midpoint(p012, p123);
+
+    subdivide(p0, p01, p012, center, tolerance, depth + 1, out);
+    subdivide(center, p123, p23, p3, tolerance, depth + 1, out);
+}
+
+/// Flattens a quadratic bezier by raising it to a cubic one.
+pub fn quadratic(p0: Point, p1: Point, p2: Point, tolerance: f64, out: &mut Vec<Point>) {
+    let c1 = (
+        p0.0 + 2.0 / 3.0 * (p1.0 - p0.0),
+        p0.1 + 2.0 / 3.0 * (p1.1 - p0.1),
+    );
+    let c2 = (
+        p2.0 + 2.0 / 3.0 * (p1.0 - p2.0),
+        p2.1 + 2.0 / 3.0 * (p1.1 - p2.1),
+    );
+    cubic(p0, c1, c2, p2, tolerance, out);
+}
+
+/// Flattens an ellipse made of four cubic beziers, transformed before flattening so the
+/// tolerance holds in the final coordinate space.
+pub fn ellipse((cx, cy): Point, rx: f64, ry: f64, ts: &Transform, tolerance: f64) -> Vec<Point> {
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+    let quarters = [
+        [
+            (cx + rx, cy),
+            (cx + rx, cy + ky),
+            (cx + kx, cy + ry),
+            (cx, cy + ry),
+        ],
+        [
+            (cx, cy + ry),
+            (cx - kx, cy + ry),
+            (cx - rx, cy + ky),
+            (cx - rx, cy),
+        ],
+        [
+            (cx - rx, cy),
+            (cx - rx, cy - ky),
+            (cx - kx, cy - ry),
+            (cx, cy - ry),
+        ],
+        [
+            (cx, cy - ry),
+            (cx + kx, cy - ry),
+            (cx + rx, cy - ky),
+            (cx + rx, cy),
+        ],
+    ];
+
+    let mut out = vec![apply(ts, (cx + rx, cy))];
+    for [p0, p1, p2, p3] in quarters {
+        cubic(
+            apply(ts, p0),
+            apply(ts, p1),
+            apply(ts, p2),
+            apply(ts, p3),
+            tolerance,
+            &mut out,
+        );
+    }
+    out
+}
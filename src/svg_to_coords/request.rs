@@ -0,0 +1,241 @@
+use crate::image_to_coords::processing_utils::{interpolate_coords, scramble_coords};
+use crate::svg_to_coords::flatten::{self, Point};
+use roxmltree::Node;
+use std::str::FromStr;
+use svgtypes::{
+    Length, PointsParser, SimplePathSegment, SimplifyingPathParser, Transform, ViewBox,
+};
+
+// Elements that only hold definitions or metadata and are never drawn directly.
+const SKIPPED: [&str; 10] = [
+    "defs", "clipPath", "mask", "symbol", "marker", "pattern", "style", "title", "desc", "metadata",
+];
+
+pub struct Request<S: std::ops::Deref<Target = Vec<u8>>> {
+    pub svg: S,
+    pub tolerance: f32,
+    pub interpolate: bool,
+    pub int_amount: usize,
+    pub scramble: bool,
+    pub flatten: bool,
+    pub result: Vec<(f32, f32)>,
+    pub contour_starts: Vec<usize>,
+}
+
+fn number(node: &Node, name: &str) -> f64 {
+    node.attribute(name)
+        .and_then(|value| Length::from_str(value).ok())
+        .map(|length| length.number)
+        .unwrap_or(0.0)
+}
+
+fn transform(node: &Node) -> Transform {
+    node.attribute("transform")
+        .and_then(|value| Transform::from_str(value).ok())
+        .unwrap_or_default()
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<S: std::ops::Deref<Target = Vec<u8>>> Request<S> {
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let text = std::str::from_utf8(&self.svg)?;
+        let document = roxmltree::Document::parse(text)?;
+        let root = document.root_element();
+
+        let mut paths = vec![];
+        self.collect(root, &Transform::default(), &mut paths);
+        paths.retain(|path| path.len() > 1);
+
+        let normalized = self.normalize(&root, &paths);
+        self.result = Vec::new();
+        self.contour_starts = Vec::new();
+        match self.flatten {
+            true => {
+                let flat: Vec<(f32, f32)> = normalized.into_iter().flatten().collect();
+                self.result = self.post_process(flat);
+                self.contour_starts.push(0);
+            }
+            false => {
+                for path in normalized {
+                    let processed = self.post_process(path);
+                    self.contour_starts.push(self.result.len());
+                    self.result.extend(processed);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Same post processing the image methods use: optional scrambling and interpolation.
+    fn post_process(&self, coords: Vec<(f32, f32)>) -> Vec<(f32, f32)> {
+        let coords = match self.scramble {
+            true => scramble_coords(coords),
+            false => coords,
+        };
+        match self.interpolate {
+            true => interpolate_coords(&coords, self.int_amount),
+            false => coords,
+        }
+    }
+
+    // Walks the document and flattens every drawable element into polylines.
+    fn collect(&self, node: Node, parent: &Transform, paths: &mut Vec<Vec<Point>>) {
+        if !node.is_element() || SKIPPED.contains(&node.tag_name().name()) {
+            return;
+        }
+
+        let ts = flatten::combine(parent, &transform(&node));
+        let tolerance = self.tolerance.max(0.01) as f64;
+
+        match node.tag_name().name() {
+            "path" => {
+                if let Some(data) = node.attribute("d") {
+                    self.collect_path(data, &ts, paths);
+                }
+            }
+            "line" => paths.push(vec![
+                flatten::apply(&ts, (number(&node, "x1"), number(&node, "y1"))),
+                flatten::apply(&ts, (number(&node, "x2"), number(&node, "y2"))),
+            ]),
+            name @ ("polyline" | "polygon") => {
+                let mut path: Vec<Point> =
+                    PointsParser::from(node.attribute("points").unwrap_or(""))
+                        .map(|point| flatten::apply(&ts, point))
+                        .collect();
+                if name == "polygon" && !path.is_empty() {
+                    path.push(path[0]);
+                }
+                paths.push(path);
+            }
+            "rect" => {
+                let (x, y) = (number(&node, "x"), number(&node, "y"));
+                let (w, h) = (number(&node, "width"), number(&node, "height"));
+                paths.push(
+                    [(x, y), (x + w, y), (x + w, y + h), (x, y + h), (x, y)]
+                        .into_iter()
+                        .map(|point| flatten::apply(&ts, point))
+                        .collect(),
+                );
+            }
+            "circle" => {
+                let r = number(&node, "r");
+                let center = (number(&node, "cx"), number(&node, "cy"));
+                paths.push(flatten::ellipse(center, r, r, &ts, tolerance));
+            }
+            "ellipse" => {
+                let center = (number(&node, "cx"), number(&node, "cy"));
+                let (rx, ry) = (number(&node, "rx"), number(&node, "ry"));
+                paths.push(flatten::ellipse(center, rx, ry, &ts, tolerance));
+            }
+            _ => {}
+        }
+
+        for child in node.children() {
+            self.collect(child, &ts, paths);
+        }
+    }
+
+    // Splits path data into subpaths. Control points are transformed before flattening, so the
+    // tolerance holds in the final coordinate space.
+    fn collect_path(&self, data: &str, ts: &Transform, paths: &mut Vec<Vec<Point>>) {
+        let tolerance = self.tolerance.max(0.01) as f64;
+        let mut current: Vec<Point> = vec![];
+        let mut start = (0.0, 0.0);
+        let mut last = (0.0, 0.0);
+
+        for segment in SimplifyingPathParser::from(data) {
+            let Ok(segment) = segment else {
+                break;
+            };
+            match segment {
+                SimplePathSegment::MoveTo { x, y } => {
+                    paths.push(std::mem::take(&mut current));
+                    start = (x, y);
+                    last = start;
+                    current.push(flatten::apply(ts, start));
+                }
+                SimplePathSegment::LineTo { x, y } => {
+                    last = (x, y);
+                    current.push(flatten::apply(ts, last));
+                }
+                SimplePathSegment::CurveTo {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    x,
+                    y,
+                } => {
+                    flatten::cubic(
+                        flatten::apply(ts, last),
+                        flatten::apply(ts, (x1, y1)),
+                        flatten::apply(ts, (x2, y2)),
+                        flatten::apply(ts, (x, y)),
+                        tolerance,
+                        &mut current,
+                    );
+                    last = (x, y);
+                }
+                SimplePathSegment::Quadratic { x1, y1, x, y } => {
+                    flatten::quadratic(
+                        flatten::apply(ts, last),
+                        flatten::apply(ts, (x1, y1)),
+                        flatten::apply(ts, (x, y)),
+                        tolerance,
+                        &mut current,
+                    );
+                    last = (x, y);
+                }
+                SimplePathSegment::ClosePath => {
+                    if last != start {
+                        current.push(flatten::apply(ts, start));
+                    }
+                    last = start;
+                }
+            }
+        }
+
+        paths.push(current);
+    }
+
+    // The drawing area is the viewBox, or the size of the document when there is none, or
+    // otherwise the bounds of everything that was drawn. The longest side maps to [-1.0, 1.0].
+    fn normalize(&self, root: &Node, paths: &[Vec<Point>]) -> Vec<Vec<(f32, f32)>> {
+        let frame = root
+            .attribute("viewBox")
+            .and_then(|value| ViewBox::from_str(value).ok())
+            .map(|view_box| (view_box.x, view_box.y, view_box.w, view_box.h))
+            .or_else(|| {
+                let (w, h) = (number(root, "width"), number(root, "height"));
+                (w > 0.0 && h > 0.0).then_some((0.0, 0.0, w, h))
+            })
+            .unwrap_or_else(|| {
+                let (min_x, min_y, max_x, max_y) = paths.iter().flatten().fold(
+                    (f64::MAX, f64::MAX, f64::MIN, f64::MIN),
+                    |(min_x, min_y, max_x, max_y), &(x, y)| {
+                        (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+                    },
+                );
+                (min_x, min_y, max_x - min_x, max_y - min_y)
+            });
+
+        let (x, y, w, h) = frame;
+        let center = (x + w / 2.0, y + h / 2.0);
+        let half = (w.max(h) / 2.0).max(f64::EPSILON);
+
+        paths
+            .iter()
+            .map(|path| {
+                path.iter()
+                    .map(|(px, py)| {
+                        (
+                            ((px - center.0) / half) as f32, // X in [-1.0, 1.0]
+                            ((py - center.1) / half) as f32, // Y in [-1.0, 1.0]
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,7 @@
+/// Strokes of a single character in em units, x grows to the right from the start of the
+/// character and y grows downwards from the baseline.
+#[derive(Debug, Clone, Default)]
+pub struct Glyph {
+    pub strokes: Vec<Vec<(f32, f32)>>,
+    pub advance: f32,
+}
@@ -0,0 +1,44 @@
+use crate::text_to_coords::glyph::Glyph;
+
+// Hershey Roman Simplex, one glyph per line for the printable ascii characters starting at the
+// space. Every line holds a glyph number, the amount of vertices, the left and right bearing and
+// then the vertices, all encoded as characters relative to 'R'. " R" lifts the pen.
+const FUTURAL: &str = include_str!("futural.jhf");
+
+// Hershey glyphs are drawn on a grid where capitals are 21 units high, 32 units make an em.
+const UNITS_PER_EM: f32 = 32.0;
+
+// Row of the baseline on the Hershey grid.
+const BASELINE: i32 = 9;
+
+fn decode(c: u8) -> i32 {
+    c as i32 - b'R' as i32
+}
+
+/// Single stroke glyph for a printable ascii character.
+pub fn glyph(c: char) -> Option<Glyph> {
+    let index = (c as u32).checked_sub(' ' as u32)? as usize;
+    let line = FUTURAL.lines().nth(index)?.as_bytes();
+    let data = line.get(8..)?;
+    let (left, right) = (decode(*data.first()?), decode(*data.get(1)?));
+
+    let mut strokes = vec![];
+    let mut stroke: Vec<(f32, f32)> = vec![];
+    for pair in data[2..].chunks_exact(2) {
+        if pair == b" R" {
+            strokes.push(std::mem::take(&mut stroke));
+            continue;
+        }
+        stroke.push((
+            (decode(pair[0]) - left) as f32 / UNITS_PER_EM,
+            (decode(pair[1]) - BASELINE) as f32 / UNITS_PER_EM,
+        ));
+    }
+    strokes.push(stroke);
+    strokes.retain(|stroke| stroke.len() > 1);
+
+    Some(Glyph {
+        strokes,
+        advance: (right - left) as f32 / UNITS_PER_EM,
+    })
+}
@@ -0,0 +1,4 @@
+mod glyph;
+mod hershey;
+mod outline;
+pub mod request;
@@ -0,0 +1,85 @@
+use crate::svg_to_coords::flatten::{self, Point};
+use crate::text_to_coords::glyph::Glyph;
+use ttf_parser::{Face, OutlineBuilder};
+
+// Largest distance in em between a flattened curve and the real outline.
+const CURVE_TOLERANCE: f64 = 0.002;
+
+// Collects the outline of a glyph as closed polylines in em units.
+struct Outline {
+    scale: f64,
+    strokes: Vec<Vec<Point>>,
+    current: Vec<Point>,
+}
+
+impl Outline {
+    // Fonts have y pointing upwards, the coords have it pointing downwards.
+    fn point(&self, x: f32, y: f32) -> Point {
+        (x as f64 * self.scale, -y as f64 * self.scale)
+    }
+
+    fn last(&self) -> Point {
+        self.current.last().copied().unwrap_or((0.0, 0.0))
+    }
+}
+
+impl OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.close();
+        let point = self.point(x, y);
+        self.current.push(point);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.current.push(point);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (p0, p1, p2) = (self.last(), self.point(x1, y1), self.point(x, y));
+        flatten::quadratic(p0, p1, p2, CURVE_TOLERANCE, &mut self.current);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (p0, p1) = (self.last(), self.point(x1, y1));
+        let (p2, p3) = (self.point(x2, y2), self.point(x, y));
+        flatten::cubic(p0, p1, p2, p3, CURVE_TOLERANCE, &mut self.current);
+    }
+
+    fn close(&mut self) {
+        if let Some(&start) = self.current.first() {
+            if self.last() != start {
+                self.current.push(start);
+            }
+            self.strokes.push(std::mem::take(&mut self.current));
+        }
+    }
+}
+
+/// Outline glyph of a character, the outlines are drawn as closed strokes.
+pub fn glyph(face: &Face, c: char) -> Option<Glyph> {
+    let id = face.glyph_index(c)?;
+    let scale = 1.0 / face.units_per_em().max(1) as f64;
+    let mut outline = Outline {
+        scale,
+        strokes: vec![],
+        current: vec![],
+    };
+    face.outline_glyph(id, &mut outline);
+    outline.close();
+
+    Some(Glyph {
+        strokes: outline
+            .strokes
+            .into_iter()
+            .filter(|stroke| stroke.len() > 1)
+            .map(|stroke| {
+                stroke
+                    .into_iter()
+                    .map(|(x, y)| (x as f32, y as f32))
+                    .collect()
+            })
+            .collect(),
+        advance: face.glyph_hor_advance(id).unwrap_or(0) as f32 * scale as f32,
+    })
+}
@@ -0,0 +1,96 @@
+use crate::text_to_coords::glyph::Glyph;
+use crate::text_to_coords::{hershey, outline};
+use std::sync::Arc;
+use ttf_parser::Face;
+
+// Half the height of a capital in em, used to center the text block vertically.
+const HALF_CAP_HEIGHT: f32 = 0.33;
+
+pub struct Request<T: std::ops::Deref<Target = String>> {
+    pub text: T,
+    pub font: Option<Arc<Vec<u8>>>,
+    pub text_size: f32,
+    pub text_align: u32,
+    pub line_spacing: f32,
+    pub interpolate: bool,
+    pub int_amount: usize,
+    pub flatten: bool,
+    pub result: Vec<(f32, f32)>,
+    pub contour_starts: Vec<usize>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<T: std::ops::Deref<Target = String>> Request<T> {
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let face = match &self.font {
+            Some(font) => Some(Face::parse(font, 0)?),
+            None => None,
+        };
+        let glyph = |c: char| -> Glyph {
+            let found = match &face {
+                Some(face) => outline::glyph(face, c),
+                None => hershey::glyph(c),
+            };
+            // Characters the font doesn't have are drawn as a question mark.
+            found.or_else(|| hershey::glyph('?')).unwrap_or_default()
+        };
+
+        let lines: Vec<Vec<Glyph>> = self
+            .text
+            .lines()
+            .map(|line| line.replace('\t', "    ").chars().map(glyph).collect())
+            .collect();
+
+        let size = self.text_size;
+        let line_height = size * self.line_spacing;
+        let first_baseline =
+            -(lines.len().saturating_sub(1) as f32) * line_height / 2.0 + HALF_CAP_HEIGHT * size;
+
+        self.result = Vec::new();
+        self.contour_starts = Vec::new();
+        for (row, line) in lines.iter().enumerate() {
+            let width: f32 = line.iter().map(|g| g.advance).sum::<f32>() * size;
+            let mut x = match self.text_align {
+                0 => -1.0,         // Left
+                2 => 1.0 - width,  // Right
+                _ => -width / 2.0, // Center
+            };
+            let baseline = first_baseline + row as f32 * line_height;
+
+            for glyph in line {
+                for stroke in &glyph.strokes {
+                    let placed: Vec<(f32, f32)> = stroke
+                        .iter()
+                        .map(|(gx, gy)| (x + gx * size, baseline + gy * size))
+                        .collect();
+                    self.push_stroke(&placed);
+                }
+                x += glyph.advance * size;
+            }
+        }
+
+        if self.flatten {
+            self.contour_starts = vec![0];
+        }
+        Ok(())
+    }
+
+    // Strokes are open lines, so unlike the closed contours of the image methods the last point
+    // is not connected back to the first one.
+    fn push_stroke(&mut self, stroke: &[(f32, f32)]) {
+        self.contour_starts.push(self.result.len());
+        if !self.interpolate {
+            self.result.extend_from_slice(stroke);
+            return;
+        }
+        for pair in stroke.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            for j in 0..self.int_amount {
+                let t = j as f32 / self.int_amount as f32;
+                self.result.push((x0 + t * (x1 - x0), y0 + t * (y1 - y0)));
+            }
+        }
+        self.result.extend(stroke.last());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::automation::curve::Automation;
+use crate::backend::settings::Settings;
+use crate::transform_coords::transform::Transform;
+
+pub struct Request<C, A>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+    A: std::ops::Deref<Target = Automation>,
+{
+    pub coords: C,
+    pub automation: Option<A>,
+    pub settings: Settings,
+    pub sample_rate: u32,
+    pub frame_rate: f32,
+    pub duration: f64,
+    pub result: Vec<(f32, f32)>,
+}
+
+/// Every request gets handeled in the Request struct for easy accessibility to variables and to
+/// make it easy to refactor or add useability later.
+impl<C, A> Request<C, A>
+where
+    C: std::ops::Deref<Target = Vec<(f32, f32)>>,
+    A: std::ops::Deref<Target = Automation>,
+{
+    // Every sample is transformed at its own time, counted from the start of the coords. With
+    // automation the transform settings are evaluated once per frame, like automated traces.
+    // The player loops the result, so a transform that changes over time first loops the coords
+    // out to the clip duration, otherwise it would jump back at the end of every trace.
+    pub fn process(&mut self) -> Result<(), anyhow::Error> {
+        let sample_rate = self.sample_rate.max(1) as f32;
+        let segment_samples = self.segment_samples();
+
+        // Only automation of the transform settings changes the result, automation of other
+        // settings keeps the fast path.
+        let automation = self
+            .automation
+            .as_deref()
+            .filter(|automation| automation.automates(&Transform::SETTINGS));
+        let mut transform = Transform::from_settings(&self.settings);
+        if automation.is_none() && transform.is_identity() {
+            self.result = self.coords.to_vec();
+            return Ok(());
+        }
+
+        let length = match automation.is_some() || transform.is_time_varying() {
+            true => self.clip_samples().max(self.coords.len()),
+            false => self.coords.len(),
+        };
+        self.result = Vec::with_capacity(length);
+        for (i, &point) in self.coords.iter().cycle().take(length).enumerate() {
+            if let Some(automation) = automation
+                && i % segment_samples == 0
+            {
+                let time = i as f64 / sample_rate as f64;
+                transform =
+                    Transform::from_settings(&automation.settings_at(&self.settings, time)?);
+            }
+            let time = i as f32 / sample_rate;
+            self.result.push(transform.at(time).apply(point));
+        }
+        Ok(())
+    }
+
+    fn clip_samples(&self) -> usize {
+        (self.duration.max(0.0) * self.sample_rate as f64).round() as usize
+    }
+
+    fn segment_samples(&self) -> usize {
+        let frame_rate = self.frame_rate.max(f32::EPSILON);
+        ((self.sample_rate as f32 / frame_rate).round() as usize).max(1)
+    }
+}
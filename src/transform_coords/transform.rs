@@ -0,0 +1,134 @@
+use crate::backend::settings::Settings;
+use std::f32::consts::TAU;
+
+/// Geometric transform of the coords stream. The warps are applied first, around the center of
+/// the drawing, then mirroring, shearing, scaling, rotating (in degrees) and translating.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub rotate: f32,
+    pub scale: (f32, f32),
+    pub translate: (f32, f32),
+    pub shear: (f32, f32),
+    pub mirror: (bool, bool),
+    /// Positive values bulge the center outwards, negative values pinch it, the edge of the
+    /// unit circle stays in place.
+    pub fisheye: f32,
+    /// Degrees the center is turned, fading out towards the edge of the unit circle.
+    pub swirl: f32,
+    pub wave_amplitude: f32,
+    /// Waves across the drawing, the width of the drawing is two.
+    pub wave_frequency: f32,
+    pub wave_phase: f32,
+    /// Degrees per second the rotation turns.
+    pub spin: f32,
+    /// Waves per second that travel through the drawing.
+    pub wave_speed: f32,
+}
+
+impl Transform {
+    /// The settings the transform is built from, by the camel case names automation uses.
+    pub const SETTINGS: [&str; 15] = [
+        "transformRotate",
+        "transformScaleX",
+        "transformScaleY",
+        "transformTranslateX",
+        "transformTranslateY",
+        "transformShearX",
+        "transformShearY",
+        "mirrorX",
+        "mirrorY",
+        "fisheye",
+        "swirl",
+        "waveAmplitude",
+        "waveFrequency",
+        "transformSpin",
+        "waveSpeed",
+    ];
+
+    pub fn from_settings(settings: &Settings) -> Transform {
+        Transform {
+            rotate: settings.transform_rotate,
+            scale: (settings.transform_scale_x, settings.transform_scale_y),
+            translate: (
+                settings.transform_translate_x,
+                settings.transform_translate_y,
+            ),
+            shear: (settings.transform_shear_x, settings.transform_shear_y),
+            mirror: (settings.mirror_x, settings.mirror_y),
+            fisheye: settings.fisheye,
+            swirl: settings.swirl,
+            wave_amplitude: settings.wave_amplitude,
+            wave_frequency: settings.wave_frequency,
+            wave_phase: 0.0,
+            spin: settings.transform_spin,
+            wave_speed: settings.wave_speed,
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.rotate == 0.0
+            && self.scale == (1.0, 1.0)
+            && self.translate == (0.0, 0.0)
+            && self.shear == (0.0, 0.0)
+            && self.mirror == (false, false)
+            && self.fisheye == 0.0
+            && self.swirl == 0.0
+            && self.wave_amplitude == 0.0
+            && self.spin == 0.0
+    }
+
+    /// Whether the transform changes over time, a spin or a travelling wave.
+    pub fn is_time_varying(&self) -> bool {
+        self.spin != 0.0 || (self.wave_amplitude != 0.0 && self.wave_speed != 0.0)
+    }
+
+    /// The transform at a time in seconds, with the spin and the travelling waves advanced.
+    pub fn at(&self, time: f32) -> Transform {
+        Transform {
+            rotate: self.rotate + self.spin * time,
+            wave_phase: self.wave_phase + self.wave_speed * time * TAU,
+            ..*self
+        }
+    }
+
+    pub fn apply(&self, point: (f32, f32)) -> (f32, f32) {
+        let (x, y) = self.warp(point);
+        let x = if self.mirror.0 { -x } else { x };
+        let y = if self.mirror.1 { -y } else { y };
+        let (x, y) = (x + self.shear.0 * y, y + self.shear.1 * x);
+        let (x, y) = (x * self.scale.0, y * self.scale.1);
+        let (sin, cos) = self.rotate.to_radians().sin_cos();
+        (
+            x * cos - y * sin + self.translate.0,
+            x * sin + y * cos + self.translate.1,
+        )
+    }
+
+    fn warp(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (mut x, mut y) = (x, y);
+
+        if self.fisheye != 0.0 || self.swirl != 0.0 {
+            let r = (x * x + y * y).sqrt();
+            if r > f32::EPSILON {
+                // Barrel distortion that maps the unit circle onto itself.
+                let k = self.fisheye.max(-0.99);
+                let warped = match r < 1.0 {
+                    true => r * (1.0 + k) / (1.0 + k * r),
+                    false => r,
+                };
+                let angle = y.atan2(x) + self.swirl.to_radians() * (1.0 - r).max(0.0);
+                (x, y) = (warped * angle.cos(), warped * angle.sin());
+            }
+        }
+
+        if self.wave_amplitude != 0.0 {
+            let frequency = self.wave_frequency * TAU / 2.0;
+            (x, y) = (
+                x + self.wave_amplitude * (y * frequency + self.wave_phase).sin(),
+                y + self.wave_amplitude * (x * frequency + self.wave_phase).sin(),
+            );
+        }
+
+        (x, y)
+    }
+}
@@ -0,0 +1,145 @@
+use rand::Rng;
+use wasm_bindgen::prelude::*;
+
+// Samples whose weight drops below this are not drawn any more, it is one step of an 8 bit alpha.
+const MIN_WEIGHT: f32 = 1.0 / 255.0;
+
+/// Point generation for the visualizer that keeps its state between frames. The front end
+/// writes the samples of a frame straight into the input buffers through views on the wasm
+/// memory, calls `update` once, and reads the vertices back the same way, so no call crosses
+/// between JS and wasm per sample.
+///
+/// The whole analyser window is drawn every frame at full weight. The analyser windows of
+/// consecutive frames overlap, so only the samples that drop out of the window are added to the
+/// history ring, together with the last frame that drew them. Every vertex is `x, y, weight`,
+/// from the oldest sample to the newest one, and the weight of the history fades by the
+/// persistence for every frame since the sample left the window.
+#[wasm_bindgen]
+pub struct Visualizer {
+    block_size: usize,
+    left: Vec<f32>,
+    right: Vec<f32>,
+    // The window of the last frame, the start of it is what drops out in the next frame.
+    window: Vec<(f32, f32)>,
+    // Samples that left the window with the last frame they were drawn in, `head` is where the
+    // next sample goes.
+    ring: Vec<(f32, f32, u32)>,
+    head: usize,
+    filled: usize,
+    frame: u32,
+    vertices: Vec<f32>,
+    scale: f32,
+    center: (f32, f32),
+    noise: f32,
+    persistence: f32,
+}
+
+#[wasm_bindgen]
+impl Visualizer {
+    /// `block_size` is the size of the analyser window, `history` the most samples that are
+    /// kept after they left the window.
+    #[wasm_bindgen(constructor)]
+    pub fn new(block_size: usize, history: usize) -> Visualizer {
+        let history = history.max(1);
+        Visualizer {
+            block_size,
+            left: vec![0.0; block_size],
+            right: vec![0.0; block_size],
+            window: Vec::with_capacity(block_size),
+            ring: vec![(0.0, 0.0, 0); history],
+            head: 0,
+            filled: 0,
+            frame: 0,
+            vertices: Vec::with_capacity((block_size + history) * 3),
+            scale: 300.0,
+            center: (300.0, 300.0),
+            noise: 0.0,
+            persistence: 0.0,
+        }
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    pub fn left_ptr(&mut self) -> *mut f32 {
+        self.left.as_mut_ptr()
+    }
+
+    pub fn right_ptr(&mut self) -> *mut f32 {
+        self.right.as_mut_ptr()
+    }
+
+    pub fn vertices_ptr(&self) -> *const f32 {
+        self.vertices.as_ptr()
+    }
+
+    pub fn set_view(&mut self, scale: f32, center_x: f32, center_y: f32) {
+        self.scale = scale;
+        self.center = (center_x, center_y);
+    }
+
+    /// Random offset of every vertex, in pixels.
+    pub fn set_noise(&mut self, noise: f32) {
+        self.noise = noise.max(0.0);
+    }
+
+    /// Percentage of a frame that is left in the next frame, from 0 to 99.
+    pub fn set_persistence(&mut self, persistence: f32) {
+        self.persistence = persistence.clamp(0.0, 99.0);
+    }
+
+    /// Forgets every sample, for when playback starts over.
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.head = 0;
+        self.filled = 0;
+        self.vertices.clear();
+    }
+
+    /// Takes the input buffers as the new window, `samples` is the amount of samples that
+    /// arrived since the previous frame, and rebuilds the vertices. Returns the amount of
+    /// vertices.
+    pub fn update(&mut self, samples: usize) -> usize {
+        let capacity = self.ring.len();
+        let left = samples.min(self.window.len());
+        for &(x, y) in &self.window[..left] {
+            self.ring[self.head] = (x, y, self.frame);
+            self.head = (self.head + 1) % capacity;
+            self.filled = (self.filled + 1).min(capacity);
+        }
+        self.frame = self.frame.wrapping_add(1);
+        self.window.clear();
+        self.window
+            .extend(self.left.iter().copied().zip(self.right.iter().copied()));
+
+        // Every frame keeps `fade` of the frame before it, the history is drawn until it is
+        // faded out or overwritten.
+        let fade = self.persistence / 100.0;
+        let mut rng = rand::rng();
+        self.vertices.clear();
+        let history = (0..self.filled).map(|i| {
+            let (x, y, frame) = self.ring[(self.head + capacity - self.filled + i) % capacity];
+            (x, y, fade.powi(self.frame.wrapping_sub(frame) as i32))
+        });
+        let window = self.window.iter().map(|&(x, y)| (x, y, 1.0));
+        for (x, y, weight) in history.chain(window) {
+            if weight < MIN_WEIGHT {
+                continue;
+            }
+            let (noise_x, noise_y) = match self.noise > 0.0 {
+                true => (
+                    rng.random_range(-1.0..=1.0) * self.noise,
+                    rng.random_range(-1.0..=1.0) * self.noise,
+                ),
+                false => (0.0, 0.0),
+            };
+            self.vertices.extend([
+                self.center.0 + x * self.scale + noise_x,
+                self.center.1 + y * self.scale + noise_y,
+                weight,
+            ]);
+        }
+        self.vertices.len() / 3
+    }
+}